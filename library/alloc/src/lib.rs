@@ -250,6 +250,8 @@ mod boxed {
 pub mod task;
 #[cfg(test)]
 mod tests;
+#[cfg(not(no_global_oom_handling))]
+mod time;
 pub mod vec;
 
 #[doc(hidden)]
@@ -0,0 +1,246 @@
+//! Methods on [`Duration`] that allocate.
+
+use core::iter;
+use core::time::Duration;
+
+use crate::string::{String, ToString};
+use crate::vec::Vec;
+
+/// The maximum number of digits [`Duration::to_human_readable_string`] writes
+/// after the decimal point. An amount that is an exact decimal in its unit never
+/// needs more than 16, so further digits would only repeat a fraction.
+const MAX_PRECISION: usize = 20;
+
+/// The units used by [`Duration::to_human_readable_string`], from the largest
+/// to the smallest, with their singular and plural names and their length in
+/// nanoseconds.
+const UNITS: [(&str, &str, u128); 8] = [
+    ("week", "weeks", 7 * 24 * 60 * 60 * 1_000_000_000),
+    ("day", "days", 24 * 60 * 60 * 1_000_000_000),
+    ("hour", "hours", 60 * 60 * 1_000_000_000),
+    ("minute", "minutes", 60 * 1_000_000_000),
+    ("second", "seconds", 1_000_000_000),
+    ("millisecond", "milliseconds", 1_000_000),
+    ("microsecond", "microseconds", 1_000),
+    ("nanosecond", "nanoseconds", 1),
+];
+
+#[cfg(not(test))]
+impl Duration {
+    /// Formats this `Duration` as its whole seconds and nanoseconds in
+    /// hexadecimal, zero-padded to 16 and 8 digits and separated by a colon
+    /// (`SSSSSSSSSSSSSSSS:NNNNNNNN`).
+    ///
+    /// This is the format used by many hardware trace tools to print raw
+    /// counter values. Lowercase digits are used if `uppercase` is `false`. The
+    /// result can be parsed back with [`Duration::from_hex_str`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_hex_string)]
+    /// use std::time::Duration;
+    ///
+    /// let duration = Duration::new(255, 999_999_999);
+    /// assert_eq!(duration.to_hex_string(false), "00000000000000ff:3b9ac9ff");
+    /// assert_eq!(duration.to_hex_string(true), "00000000000000FF:3B9AC9FF");
+    /// ```
+    #[rustc_allow_incoherent_impl]
+    #[unstable(feature = "duration_hex_string", issue = "none")]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn to_hex_string(&self, uppercase: bool) -> String {
+        if uppercase {
+            format!("{:016X}:{:08X}", self.as_secs(), self.subsec_nanos())
+        } else {
+            format!("{:016x}:{:08x}", self.as_secs(), self.subsec_nanos())
+        }
+    }
+
+    /// Formats this `Duration` as a human-readable amount of the largest unit
+    /// of time it contains at least once, such as `"1.5 seconds"` or
+    /// `"3 hours"`.
+    ///
+    /// The amount is rounded to at most `precision` digits after the decimal
+    /// point, with halfway cases rounded up, and trailing zeros are removed.
+    /// The units are the same as the ones accepted by
+    /// [`Duration::parse_human_readable`], so the result can be parsed back
+    /// into a `Duration`. A zero `Duration` is formatted as `"0 seconds"`.
+    ///
+    /// If rounding carries the amount up to one of the next larger unit, that
+    /// unit is used instead, so 59.999 seconds with a `precision` of 2 is
+    /// formatted as `"1 minute"`. A `precision` of more than 20 is treated as 20:
+    /// amounts in minutes, hours, days and weeks can have infinitely many
+    /// digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_human_readable)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::from_millis(1500).to_human_readable_string(2), "1.5 seconds");
+    /// assert_eq!(Duration::from_millis(1500).to_human_readable_string(0), "2 seconds");
+    /// assert_eq!(Duration::from_secs(60).to_human_readable_string(3), "1 minute");
+    /// assert_eq!(Duration::from_secs(100).to_human_readable_string(3), "1.667 minutes");
+    /// assert_eq!(Duration::from_millis(59_999).to_human_readable_string(2), "1 minute");
+    /// assert_eq!(Duration::from_nanos(1_001).to_human_readable_string(1), "1 microsecond");
+    /// assert_eq!(Duration::ZERO.to_human_readable_string(3), "0 seconds");
+    /// ```
+    #[rustc_allow_incoherent_impl]
+    #[unstable(feature = "duration_human_readable", issue = "none")]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn to_human_readable_string(&self, precision: usize) -> String {
+        let nanos = self.as_nanos();
+        let index = match UNITS.iter().position(|&(_, _, unit)| nanos >= unit) {
+            Some(index) => index,
+            None => 4,
+        };
+        let (mut singular, mut plural, unit) = UNITS[index];
+
+        let mut whole = nanos / unit;
+        let mut rem = nanos % unit;
+        let mut digits = Vec::new();
+        while digits.len() < precision.min(MAX_PRECISION) && rem != 0 {
+            rem *= 10;
+            digits.push((rem / unit) as u8);
+            rem %= unit;
+        }
+
+        // Round half up, propagating the carry through the digits.
+        if rem * 2 >= unit {
+            loop {
+                match digits.last_mut() {
+                    Some(9) => {
+                        digits.pop();
+                    }
+                    Some(digit) => {
+                        *digit += 1;
+                        break;
+                    }
+                    None => {
+                        whole += 1;
+                        break;
+                    }
+                }
+            }
+        }
+        // The carry can make the amount exactly one of the next larger unit.
+        if index > 0 && whole * unit == UNITS[index - 1].2 {
+            whole = 1;
+            (singular, plural, _) = UNITS[index - 1];
+        }
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+
+        let mut s = whole.to_string();
+        if !digits.is_empty() {
+            s.push('.');
+            s.extend(digits.iter().map(|&digit| (b'0' + digit) as char));
+        }
+        s.push(' ');
+        s.push_str(if whole == 1 && digits.is_empty() { singular } else { plural });
+        s
+    }
+
+    /// Formats this `Duration` as a decimal number of seconds with exactly
+    /// `decimal_places` digits after the decimal point, such as `"1.500"`.
+    ///
+    /// Unlike the [`Debug`] impl, this always uses seconds as the unit. The
+    /// nanoseconds are truncated to `decimal_places` digits, and padded with
+    /// zeros if `decimal_places` is greater than 9. If `decimal_places` is zero,
+    /// only the whole seconds are formatted, without a decimal point.
+    ///
+    /// [`Debug`]: core::fmt::Debug
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_decimal_seconds)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::from_millis(1500).to_decimal_seconds_string(3), "1.500");
+    /// assert_eq!(Duration::from_millis(1999).to_decimal_seconds_string(1), "1.9");
+    /// assert_eq!(Duration::from_millis(1500).to_decimal_seconds_string(0), "1");
+    /// assert_eq!(Duration::new(1, 5).to_decimal_seconds_string(10), "1.0000000050");
+    /// ```
+    #[rustc_allow_incoherent_impl]
+    #[unstable(feature = "duration_decimal_seconds", issue = "none")]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn to_decimal_seconds_string(&self, decimal_places: usize) -> String {
+        let mut s = self.as_secs().to_string();
+        if decimal_places == 0 {
+            return s;
+        }
+        s.reserve(decimal_places + 1);
+        s.push('.');
+        let nanos = self.subsec_nanos();
+        let mut divisor = 100_000_000;
+        for _ in 0..decimal_places.min(9) {
+            s.push((b'0' + (nanos / divisor % 10) as u8) as char);
+            divisor /= 10;
+        }
+        s.extend(iter::repeat('0').take(decimal_places.saturating_sub(9)));
+        s
+    }
+
+    /// Returns the median of `samples`, which may be in any order.
+    ///
+    /// This sorts a copy of `samples` and then computes the same result as
+    /// [`Duration::median`]: for an even number of samples, the midpoint of the
+    /// two middle ones, rounded down to whole nanoseconds.
+    ///
+    /// Returns [`None`] if `samples` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_stats)]
+    /// use std::time::Duration;
+    ///
+    /// let samples = [40, 10, 30, 20].map(Duration::from_millis);
+    /// assert_eq!(Duration::median_unsorted(&samples), Some(Duration::from_millis(25)));
+    /// assert_eq!(Duration::median_unsorted(&[]), None);
+    /// ```
+    #[rustc_allow_incoherent_impl]
+    #[unstable(feature = "duration_stats", issue = "none")]
+    #[must_use]
+    pub fn median_unsorted(samples: &[Duration]) -> Option<Duration> {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        Duration::median(&sorted)
+    }
+
+    /// Counts how many of `samples` fall into each of the buckets delimited by
+    /// the boundaries in `buckets`.
+    ///
+    /// The boundaries must be sorted in ascending order, which is checked in
+    /// debug builds only. Each bucket is half-open, so that a sample equal to
+    /// a boundary is counted in the bucket that starts at it. The result has
+    /// `buckets.len() + 1` elements: the first counts the samples shorter than
+    /// `buckets[0]`, element `i` the samples from `buckets[i - 1]` up to, but
+    /// not including, `buckets[i]`, and the last one the samples at least as
+    /// long as the last boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_stats)]
+    /// use std::time::Duration;
+    ///
+    /// let samples = [1, 5, 10, 12, 50, 99, 100, 250].map(Duration::from_millis);
+    /// let buckets = [10, 100].map(Duration::from_millis);
+    /// assert_eq!(Duration::histogram(&samples, &buckets), [2, 4, 2]);
+    /// ```
+    #[rustc_allow_incoherent_impl]
+    #[unstable(feature = "duration_stats", issue = "none")]
+    #[must_use]
+    pub fn histogram(samples: &[Duration], buckets: &[Duration]) -> Vec<u64> {
+        debug_assert!(buckets.windows(2).all(|w| w[0] <= w[1]), "buckets are not sorted");
+        let mut counts = vec![0; buckets.len() + 1];
+        for sample in samples {
+            counts[buckets.partition_point(|boundary| boundary <= sample)] += 1;
+        }
+        counts
+    }
+}
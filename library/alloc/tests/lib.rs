@@ -14,6 +14,11 @@
 #![feature(const_try)]
 #![feature(core_intrinsics)]
 #![feature(drain_filter)]
+#![feature(duration_decimal_seconds)]
+#![feature(duration_hex_string)]
+#![feature(duration_human_readable)]
+#![feature(duration_parse)]
+#![feature(duration_stats)]
 #![feature(exact_size_is_empty)]
 #![feature(linked_list_cursors)]
 #![feature(map_try_insert)]
@@ -69,6 +74,7 @@
 mod str;
 mod string;
 mod thin_box;
+mod time;
 mod vec;
 mod vec_deque;
 
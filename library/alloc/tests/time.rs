@@ -0,0 +1,115 @@
+use std::time::Duration;
+
+#[test]
+fn to_hex_string() {
+    assert_eq!(Duration::ZERO.to_hex_string(false), "0000000000000000:00000000");
+    assert_eq!(Duration::new(0xabc, 0xde).to_hex_string(false), "0000000000000abc:000000de");
+    assert_eq!(Duration::new(0xabc, 0xde).to_hex_string(true), "0000000000000ABC:000000DE");
+    assert_eq!(Duration::MAX.to_hex_string(false), "ffffffffffffffff:3b9ac9ff");
+    assert_eq!(Duration::MAX.to_hex_string(true), "FFFFFFFFFFFFFFFF:3B9AC9FF");
+    for duration in [Duration::ZERO, Duration::new(1, 1), Duration::MAX] {
+        assert_eq!(Duration::from_hex_str(&duration.to_hex_string(false)), Ok(duration));
+        assert_eq!(Duration::from_hex_str(&duration.to_hex_string(true)), Ok(duration));
+    }
+}
+
+#[test]
+fn to_human_readable_string() {
+    let s = |d: Duration, precision| d.to_human_readable_string(precision);
+    assert_eq!(s(Duration::ZERO, 0), "0 seconds");
+    assert_eq!(s(Duration::from_nanos(1), 0), "1 nanosecond");
+    assert_eq!(s(Duration::from_nanos(999), 3), "999 nanoseconds");
+    assert_eq!(s(Duration::from_nanos(1_500), 0), "2 microseconds");
+    assert_eq!(s(Duration::from_nanos(1_499), 0), "1 microsecond");
+    assert_eq!(s(Duration::from_millis(1_500), 2), "1.5 seconds");
+    assert_eq!(s(Duration::from_millis(1_500), 0), "2 seconds");
+    assert_eq!(s(Duration::from_millis(1_000), 5), "1 second");
+    assert_eq!(s(Duration::from_millis(1_001), 2), "1 second");
+    assert_eq!(s(Duration::from_millis(1_999), 2), "2 seconds");
+    assert_eq!(s(Duration::from_millis(1_995), 2), "2 seconds");
+    assert_eq!(s(Duration::from_millis(1_994), 2), "1.99 seconds");
+    assert_eq!(s(Duration::from_secs(20), 1), "20 seconds");
+    assert_eq!(s(Duration::from_secs(90), 1), "1.5 minutes");
+    assert_eq!(s(Duration::from_secs(3_599), 1), "1 hour");
+    assert_eq!(s(Duration::from_secs(7_200), 1), "2 hours");
+    assert_eq!(s(Duration::from_secs(86_400), 1), "1 day");
+    assert_eq!(s(Duration::from_secs(604_800 * 3), 1), "3 weeks");
+    assert_eq!(s(Duration::from_millis(59_999), 0), "1 minute");
+    assert_eq!(s(Duration::from_nanos(999_999), 2), "1 millisecond");
+    assert_eq!(s(Duration::from_secs(604_800 * 7 - 1), 3), "7 weeks");
+    assert_eq!(s(Duration::from_secs(200), 20), "3.33333333333333333333 minutes");
+    assert_eq!(s(Duration::from_secs(200), usize::MAX), "3.33333333333333333333 minutes");
+    assert_eq!(s(Duration::new(1, 1), usize::MAX), "1.000000001 seconds");
+    assert_eq!(s(Duration::MAX, 0), "30500568904943 weeks");
+}
+
+#[test]
+fn to_human_readable_string_round_trip() {
+    // Only amounts that are exact decimals in their unit survive the trip.
+    for d in [
+        Duration::ZERO,
+        Duration::from_nanos(123),
+        Duration::from_micros(4_567),
+        Duration::from_millis(1_500),
+        Duration::from_secs(90),
+        Duration::from_secs(5_400),
+        Duration::from_secs(86_400 * 14),
+        Duration::new(604_800 + 302_400, 0),
+        Duration::from_secs(604_800 * 1_000_000_000_000),
+    ] {
+        let parsed = Duration::parse_human_readable(&d.to_human_readable_string(30));
+        assert_eq!(parsed, Ok(d));
+    }
+}
+
+#[test]
+fn median_unsorted() {
+    let ms = Duration::from_millis;
+    assert_eq!(Duration::median_unsorted(&[]), None);
+    assert_eq!(Duration::median_unsorted(&[ms(5)]), Some(ms(5)));
+    assert_eq!(Duration::median_unsorted(&[ms(9), ms(1), ms(5)]), Some(ms(5)));
+    assert_eq!(
+        Duration::median_unsorted(&[ms(9), ms(1), ms(5), ms(2)]),
+        Some(Duration::from_micros(3_500))
+    );
+    let samples = [Duration::MAX, Duration::ZERO, Duration::MAX, Duration::ZERO];
+    assert_eq!(Duration::median_unsorted(&samples), Some(Duration::MAX / 2));
+}
+
+#[test]
+fn histogram() {
+    let ms = Duration::from_millis;
+    assert_eq!(Duration::histogram(&[], &[]), [0]);
+    assert_eq!(Duration::histogram(&[ms(1), Duration::MAX], &[]), [2]);
+    assert_eq!(Duration::histogram(&[], &[ms(1), ms(2)]), [0, 0, 0]);
+
+    let samples = [ms(0), ms(9), ms(10), ms(11), ms(19), ms(20), ms(21), Duration::MAX];
+    assert_eq!(Duration::histogram(&samples, &[ms(10), ms(20)]), [2, 3, 3]);
+    assert_eq!(Duration::histogram(&samples, &[Duration::ZERO]), [0, 8]);
+    assert_eq!(Duration::histogram(&samples, &[Duration::MAX]), [7, 1]);
+    // An empty bucket between two equal boundaries.
+    assert_eq!(Duration::histogram(&samples, &[ms(10), ms(10)]), [2, 0, 6]);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic = "buckets are not sorted"]
+fn histogram_unsorted_buckets() {
+    let _ = Duration::histogram(&[], &[Duration::SECOND, Duration::ZERO]);
+}
+
+#[test]
+fn to_decimal_seconds_string() {
+    let s = |d: Duration, decimal_places| d.to_decimal_seconds_string(decimal_places);
+    assert_eq!(s(Duration::ZERO, 0), "0");
+    assert_eq!(s(Duration::ZERO, 3), "0.000");
+    assert_eq!(s(Duration::from_millis(1_500), 0), "1");
+    assert_eq!(s(Duration::from_millis(1_500), 1), "1.5");
+    assert_eq!(s(Duration::from_millis(1_500), 3), "1.500");
+    assert_eq!(s(Duration::from_nanos(999_999_999), 8), "0.99999999");
+    assert_eq!(s(Duration::from_nanos(999_999_999), 9), "0.999999999");
+    assert_eq!(s(Duration::from_nanos(1), 9), "0.000000001");
+    assert_eq!(s(Duration::from_nanos(1), 12), "0.000000001000");
+    assert_eq!(s(Duration::from_secs(120), 2), "120.00");
+    assert_eq!(s(Duration::MAX, 9), "18446744073709551615.999999999");
+}
@@ -19,6 +19,7 @@
 mod pattern;
 mod slice;
 mod str;
+mod time;
 
 /// Returns a `rand::Rng` seeded with a consistent seed.
 ///
@@ -0,0 +1,60 @@
+use core::time::Duration;
+use rand::Rng;
+use test::{black_box, Bencher};
+
+const A: Duration = Duration::new(1_234, 567_890_123);
+const B: Duration = Duration::new(987, 654_321_098);
+
+#[bench]
+fn bench_add(b: &mut Bencher) {
+    b.iter(|| black_box(A) + black_box(B));
+}
+
+#[bench]
+fn bench_sub(b: &mut Bencher) {
+    b.iter(|| black_box(A) - black_box(B));
+}
+
+#[bench]
+fn bench_checked_add(b: &mut Bencher) {
+    b.iter(|| black_box(A).checked_add(black_box(B)));
+}
+
+#[bench]
+fn bench_mul_u32(b: &mut Bencher) {
+    b.iter(|| black_box(A) * black_box(1_000_003u32));
+}
+
+#[bench]
+fn bench_div_u32(b: &mut Bencher) {
+    b.iter(|| black_box(A) / black_box(1_000_003u32));
+}
+
+#[bench]
+fn bench_mul_f64(b: &mut Bencher) {
+    b.iter(|| black_box(A).mul_f64(black_box(1.375)));
+}
+
+#[bench]
+fn bench_div_f64(b: &mut Bencher) {
+    b.iter(|| black_box(A).div_f64(black_box(1.375)));
+}
+
+#[bench]
+fn bench_as_secs(b: &mut Bencher) {
+    b.iter(|| black_box(A).as_secs());
+}
+
+#[bench]
+fn bench_subsec_nanos(b: &mut Bencher) {
+    b.iter(|| black_box(A).subsec_nanos());
+}
+
+#[bench]
+fn bench_sum_1000(b: &mut Bencher) {
+    let mut rng = crate::bench_rng();
+    let durations: Vec<Duration> = (0..1000)
+        .map(|_| Duration::new(rng.gen_range(0..1_000_000), rng.gen_range(0..1_000_000_000)))
+        .collect();
+    b.iter(|| black_box(&durations).iter().sum::<Duration>());
+}
@@ -496,6 +496,12 @@ fn description(&self) -> &str {
 #[stable(feature = "duration_checked_float", since = "1.66.0")]
 impl Error for crate::time::TryFromFloatSecsError {}
 
+#[unstable(feature = "duration_parse", issue = "none")]
+impl Error for crate::time::ParseDurationError {}
+
+#[unstable(feature = "duration_try_sub", issue = "none")]
+impl Error for crate::time::DurationUnderflowError {}
+
 #[stable(feature = "frombyteswithnulerror_impls", since = "1.17.0")]
 impl Error for crate::ffi::FromBytesWithNulError {
     #[allow(deprecated)]
@@ -160,6 +160,8 @@
 #![feature(core_panic)]
 #![feature(char_indices_offset)]
 #![feature(duration_consts_float)]
+#![feature(duration_from_u128)]
+#![feature(duration_new_checked)]
 #![feature(maybe_uninit_uninit_array)]
 #![feature(ptr_alignment_type)]
 #![feature(ptr_metadata)]
@@ -19,15 +19,26 @@
 //! assert_eq!(total, Duration::new(10, 7));
 //! ```
 
+use crate::cmp::Ordering;
 use crate::fmt;
-use crate::iter::Sum;
-use crate::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use crate::iter::{FusedIterator, Sum};
+use crate::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Range, Sub, SubAssign};
 
 const NANOS_PER_SEC: u32 = 1_000_000_000;
 const NANOS_PER_MILLI: u32 = 1_000_000;
 const NANOS_PER_MICRO: u32 = 1_000;
 const MILLIS_PER_SEC: u64 = 1_000;
 const MICROS_PER_SEC: u64 = 1_000_000;
+const SECS_PER_MINUTE: u64 = 60;
+const MINS_PER_HOUR: u64 = 60;
+const HOURS_PER_DAY: u64 = 24;
+const DAYS_PER_WEEK: u64 = 7;
+const CDDA_FRAMES_PER_SEC: u64 = 75;
+
+const NANOS_PER_MINUTE: u64 = SECS_PER_MINUTE * NANOS_PER_SEC as u64;
+const NANOS_PER_HOUR: u64 = MINS_PER_HOUR * NANOS_PER_MINUTE;
+const NANOS_PER_DAY: u64 = HOURS_PER_DAY * NANOS_PER_HOUR;
+const NANOS_PER_WEEK: u64 = DAYS_PER_WEEK * NANOS_PER_DAY;
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
@@ -35,14 +46,6 @@
 #[rustc_layout_scalar_valid_range_end(999_999_999)]
 struct Nanoseconds(u32);
 
-impl Default for Nanoseconds {
-    #[inline]
-    fn default() -> Self {
-        // SAFETY: 0 is within the valid range
-        unsafe { Nanoseconds(0) }
-    }
-}
-
 /// A `Duration` type to represent a span of time, typically used for system
 /// timeouts.
 ///
@@ -80,9 +83,43 @@ fn default() -> Self {
 /// program output may appear in contexts that cannot rely on full Unicode
 /// compatibility, you may wish to format `Duration` objects yourself or use a
 /// crate to do so.
+///
+/// # Serialization
+///
+/// The standard library does not depend on any serialization framework, so
+/// impls such as serde's `Serialize` and `Deserialize` for `Duration` are
+/// provided by those frameworks themselves. The recommended representation is
+/// a structure of the whole seconds as a `u64` and the nanoseconds as a `u32`,
+/// named `secs` and `nanos`. It is lossless, matches the fields of `Duration`
+/// and is what serde itself uses. Deserializing code should check untrusted
+/// values before passing them to [`Duration::new`], which panics if carrying
+/// the nanoseconds into the seconds overflows.
+///
+/// Other representations lose information or are harder to validate. A number
+/// of seconds as a floating-point value cannot represent every `Duration`
+/// exactly, and a string needs a parser. If a format must be readable by people,
+/// [`Duration::parse_human_readable`] can read strings such as `"5 minutes 30
+/// seconds"`.
+///
+/// # Byte representation
+///
+/// The memory layout of `Duration` is unspecified, so transmuting bytes into a
+/// `Duration` is never correct. Use [`Duration::from_be_bytes`] and
+/// [`Duration::to_be_bytes`] instead, which have a fixed byte order and reject
+/// invalid nanoseconds:
+///
+/// ```
+/// #![feature(duration_bytes)]
+/// use std::time::Duration;
+///
+/// let bytes = Duration::new(5, 500).to_be_bytes();
+/// assert_eq!(Duration::from_be_bytes(bytes), Some(Duration::new(5, 500)));
+/// assert_eq!(Duration::from_be_bytes([0xff; 12]), None);
+/// ```
 #[stable(feature = "duration", since = "1.3.0")]
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(not(test), rustc_diagnostic_item = "Duration")]
+#[rustc_has_incoherent_inherent_impls]
 pub struct Duration {
     secs: u64,
     nanos: Nanoseconds, // Always 0 <= nanos < NANOS_PER_SEC
@@ -196,14 +233,70 @@ impl Duration {
     #[inline]
     #[must_use]
     #[rustc_const_stable(feature = "duration_consts_2", since = "1.58.0")]
+    #[rustc_allow_const_fn_unstable(duration_new_checked)]
     pub const fn new(secs: u64, nanos: u32) -> Duration {
+        match Duration::new_checked(secs, nanos) {
+            Some(duration) => duration,
+            None => panic!("overflow in Duration::new"),
+        }
+    }
+
+    /// Creates a new `Duration` from the specified number of whole seconds and
+    /// additional nanoseconds, returning [`None`] if overflow occurred.
+    ///
+    /// If the number of nanoseconds is greater than 1 billion (the number of
+    /// nanoseconds in a second), then it will carry over into the seconds provided.
+    /// Unlike [`Duration::new`], this does not panic if the carry overflows the
+    /// seconds counter, which makes it suitable for untrusted input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_new_checked)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::new_checked(5, 1_000_000_001), Some(Duration::new(6, 1)));
+    /// assert_eq!(Duration::new_checked(u64::MAX, 1_000_000_000), None);
+    /// ```
+    #[unstable(feature = "duration_new_checked", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_new_checked", issue = "none")]
+    #[inline]
+    #[must_use]
+    pub const fn new_checked(secs: u64, nanos: u32) -> Option<Duration> {
         let secs = match secs.checked_add((nanos / NANOS_PER_SEC) as u64) {
             Some(secs) => secs,
-            None => panic!("overflow in Duration::new"),
+            None => return None,
         };
         let nanos = nanos % NANOS_PER_SEC;
         // SAFETY: nanos % NANOS_PER_SEC < NANOS_PER_SEC, therefore nanos is within the valid range
-        Duration { secs, nanos: unsafe { Nanoseconds(nanos) } }
+        Some(Duration { secs, nanos: unsafe { Nanoseconds(nanos) } })
+    }
+
+    /// Creates a new `Duration` from the specified number of whole seconds and
+    /// additional nanoseconds, saturating at [`Duration::MAX`] if overflow occurred.
+    ///
+    /// If the number of nanoseconds is greater than 1 billion (the number of
+    /// nanoseconds in a second), then it will carry over into the seconds provided.
+    /// Unlike [`Duration::new`], this never panics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_new_saturating)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::new_saturating(5, 1_000_000_001), Duration::new(6, 1));
+    /// assert_eq!(Duration::new_saturating(u64::MAX, 1_000_000_000), Duration::MAX);
+    /// ```
+    #[unstable(feature = "duration_new_saturating", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_new_saturating", issue = "none")]
+    #[inline]
+    #[must_use]
+    pub const fn new_saturating(secs: u64, nanos: u32) -> Duration {
+        match Duration::new_checked(secs, nanos) {
+            Some(duration) => duration,
+            None => Duration::MAX,
+        }
     }
 
     /// Creates a new `Duration` from the specified number of whole seconds.
@@ -268,6 +361,10 @@ pub const fn from_micros(micros: u64) -> Duration {
 
     /// Creates a new `Duration` from the specified number of nanoseconds.
     ///
+    /// This constructor never panics: even [`u64::MAX`] nanoseconds, about 584
+    /// years, are far below [`Duration::MAX`]. There is therefore no checked
+    /// variant of it.
+    ///
     /// # Examples
     ///
     /// ```
@@ -282,633 +379,3425 @@ pub const fn from_micros(micros: u64) -> Duration {
     #[must_use]
     #[inline]
     #[rustc_const_stable(feature = "duration_consts", since = "1.32.0")]
+    #[doc(alias = "opentelemetry")]
     pub const fn from_nanos(nanos: u64) -> Duration {
         Duration::new(nanos / (NANOS_PER_SEC as u64), (nanos % (NANOS_PER_SEC as u64)) as u32)
     }
 
-    /// Returns true if this `Duration` spans no time.
+    /// Creates a new `Duration` from the specified number of weeks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given number of weeks overflows the `Duration` size.
     ///
     /// # Examples
     ///
     /// ```
+    /// #![feature(duration_constructors)]
     /// use std::time::Duration;
     ///
-    /// assert!(Duration::ZERO.is_zero());
-    /// assert!(Duration::new(0, 0).is_zero());
-    /// assert!(Duration::from_nanos(0).is_zero());
-    /// assert!(Duration::from_secs(0).is_zero());
+    /// let duration = Duration::from_weeks(4);
     ///
-    /// assert!(!Duration::new(1, 1).is_zero());
-    /// assert!(!Duration::from_nanos(1).is_zero());
-    /// assert!(!Duration::from_secs(1).is_zero());
+    /// assert_eq!(4 * 7 * 24 * 60 * 60, duration.as_secs());
+    /// assert_eq!(0, duration.subsec_nanos());
     /// ```
+    #[unstable(feature = "duration_constructors", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_constructors", issue = "none")]
     #[must_use]
-    #[stable(feature = "duration_zero", since = "1.53.0")]
-    #[rustc_const_stable(feature = "duration_zero", since = "1.53.0")]
     #[inline]
-    pub const fn is_zero(&self) -> bool {
-        self.secs == 0 && self.nanos.0 == 0
+    pub const fn from_weeks(weeks: u64) -> Duration {
+        if weeks > u64::MAX / (SECS_PER_MINUTE * MINS_PER_HOUR * HOURS_PER_DAY * DAYS_PER_WEEK) {
+            panic!("overflow in Duration::from_weeks");
+        }
+        Duration::from_secs(weeks * SECS_PER_MINUTE * MINS_PER_HOUR * HOURS_PER_DAY * DAYS_PER_WEEK)
     }
 
-    /// Returns the number of _whole_ seconds contained by this `Duration`.
+    /// Creates a new `Duration` from the specified number of days.
     ///
-    /// The returned value does not include the fractional (nanosecond) part of the
-    /// duration, which can be obtained using [`subsec_nanos`].
+    /// # Panics
+    ///
+    /// Panics if the given number of days overflows the `Duration` size.
     ///
     /// # Examples
     ///
     /// ```
+    /// #![feature(duration_constructors)]
     /// use std::time::Duration;
     ///
-    /// let duration = Duration::new(5, 730023852);
-    /// assert_eq!(duration.as_secs(), 5);
-    /// ```
-    ///
-    /// To determine the total number of seconds represented by the `Duration`
-    /// including the fractional part, use [`as_secs_f64`] or [`as_secs_f32`]
+    /// let duration = Duration::from_days(7);
     ///
-    /// [`as_secs_f64`]: Duration::as_secs_f64
-    /// [`as_secs_f32`]: Duration::as_secs_f32
-    /// [`subsec_nanos`]: Duration::subsec_nanos
-    #[stable(feature = "duration", since = "1.3.0")]
-    #[rustc_const_stable(feature = "duration_consts", since = "1.32.0")]
+    /// assert_eq!(7 * 24 * 60 * 60, duration.as_secs());
+    /// assert_eq!(0, duration.subsec_nanos());
+    /// ```
+    #[unstable(feature = "duration_constructors", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_constructors", issue = "none")]
     #[must_use]
     #[inline]
-    pub const fn as_secs(&self) -> u64 {
-        self.secs
+    pub const fn from_days(days: u64) -> Duration {
+        if days > u64::MAX / (SECS_PER_MINUTE * MINS_PER_HOUR * HOURS_PER_DAY) {
+            panic!("overflow in Duration::from_days");
+        }
+        Duration::from_secs(days * SECS_PER_MINUTE * MINS_PER_HOUR * HOURS_PER_DAY)
     }
 
-    /// Returns the fractional part of this `Duration`, in whole milliseconds.
+    /// Creates a new `Duration` from the specified number of hours.
     ///
-    /// This method does **not** return the length of the duration when
-    /// represented by milliseconds. The returned number always represents a
-    /// fractional portion of a second (i.e., it is less than one thousand).
+    /// # Panics
+    ///
+    /// Panics if the given number of hours overflows the `Duration` size.
     ///
     /// # Examples
     ///
     /// ```
+    /// #![feature(duration_constructors)]
     /// use std::time::Duration;
     ///
-    /// let duration = Duration::from_millis(5432);
-    /// assert_eq!(duration.as_secs(), 5);
-    /// assert_eq!(duration.subsec_millis(), 432);
+    /// let duration = Duration::from_hours(6);
+    ///
+    /// assert_eq!(6 * 60 * 60, duration.as_secs());
+    /// assert_eq!(0, duration.subsec_nanos());
     /// ```
-    #[stable(feature = "duration_extras", since = "1.27.0")]
-    #[rustc_const_stable(feature = "duration_consts", since = "1.32.0")]
+    #[unstable(feature = "duration_constructors", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_constructors", issue = "none")]
     #[must_use]
     #[inline]
-    pub const fn subsec_millis(&self) -> u32 {
-        self.nanos.0 / NANOS_PER_MILLI
+    pub const fn from_hours(hours: u64) -> Duration {
+        if hours > u64::MAX / (SECS_PER_MINUTE * MINS_PER_HOUR) {
+            panic!("overflow in Duration::from_hours");
+        }
+        Duration::from_secs(hours * SECS_PER_MINUTE * MINS_PER_HOUR)
     }
 
-    /// Returns the fractional part of this `Duration`, in whole microseconds.
+    /// Creates a new `Duration` from the specified number of mins.
     ///
-    /// This method does **not** return the length of the duration when
-    /// represented by microseconds. The returned number always represents a
-    /// fractional portion of a second (i.e., it is less than one million).
+    /// # Panics
+    ///
+    /// Panics if the given number of mins overflows the `Duration` size.
     ///
     /// # Examples
     ///
     /// ```
+    /// #![feature(duration_constructors)]
     /// use std::time::Duration;
     ///
-    /// let duration = Duration::from_micros(1_234_567);
-    /// assert_eq!(duration.as_secs(), 1);
-    /// assert_eq!(duration.subsec_micros(), 234_567);
+    /// let duration = Duration::from_mins(10);
+    ///
+    /// assert_eq!(10 * 60, duration.as_secs());
+    /// assert_eq!(0, duration.subsec_nanos());
+    ///
+    /// // Like the other constructors, this can be used in constants.
+    /// const CONNECT_TIMEOUT: Duration = Duration::from_mins(5);
+    /// assert_eq!(CONNECT_TIMEOUT, Duration::from_secs(300));
     /// ```
-    #[stable(feature = "duration_extras", since = "1.27.0")]
-    #[rustc_const_stable(feature = "duration_consts", since = "1.32.0")]
+    #[unstable(feature = "duration_constructors", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_constructors", issue = "none")]
     #[must_use]
     #[inline]
-    pub const fn subsec_micros(&self) -> u32 {
-        self.nanos.0 / NANOS_PER_MICRO
+    pub const fn from_mins(mins: u64) -> Duration {
+        if mins > u64::MAX / (SECS_PER_MINUTE) {
+            panic!("overflow in Duration::from_mins");
+        }
+        Duration::from_secs(mins * SECS_PER_MINUTE)
     }
 
-    /// Returns the fractional part of this `Duration`, in nanoseconds.
+    /// Creates a new `Duration` from the specified number of milliseconds as a
+    /// `u128`, returning [`None`] if it does not fit in a `Duration`.
     ///
-    /// This method does **not** return the length of the duration when
-    /// represented by nanoseconds. The returned number always represents a
-    /// fractional portion of a second (i.e., it is less than one billion).
+    /// Unlike [`from_millis`], this accepts every number of milliseconds up to
+    /// [`Duration::MAX`].
     ///
     /// # Examples
     ///
     /// ```
+    /// #![feature(duration_from_u128)]
     /// use std::time::Duration;
     ///
-    /// let duration = Duration::from_millis(5010);
-    /// assert_eq!(duration.as_secs(), 5);
-    /// assert_eq!(duration.subsec_nanos(), 10_000_000);
+    /// let millis = u64::MAX as u128 * 1_000;
+    /// assert_eq!(Duration::from_millis_u128(millis), Some(Duration::from_secs(u64::MAX)));
+    /// assert_eq!(Duration::from_millis_u128(millis + 1_000), None);
     /// ```
-    #[stable(feature = "duration", since = "1.3.0")]
-    #[rustc_const_stable(feature = "duration_consts", since = "1.32.0")]
+    ///
+    /// [`from_millis`]: Duration::from_millis
+    #[unstable(feature = "duration_from_u128", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_from_u128", issue = "none")]
     #[must_use]
     #[inline]
-    pub const fn subsec_nanos(&self) -> u32 {
-        self.nanos.0
+    pub const fn from_millis_u128(millis: u128) -> Option<Duration> {
+        let secs = millis / (MILLIS_PER_SEC as u128);
+        if secs > u64::MAX as u128 {
+            return None;
+        }
+        let nanos = (millis % (MILLIS_PER_SEC as u128)) as u32 * NANOS_PER_MILLI;
+        Some(Duration::new(secs as u64, nanos))
     }
 
-    /// Returns the total number of whole milliseconds contained by this `Duration`.
+    /// Creates a new `Duration` from the specified number of microseconds as a
+    /// `u128`, returning [`None`] if it does not fit in a `Duration`.
+    ///
+    /// Unlike [`from_micros`], this accepts every number of microseconds up to
+    /// [`Duration::MAX`].
     ///
     /// # Examples
     ///
     /// ```
+    /// #![feature(duration_from_u128)]
     /// use std::time::Duration;
     ///
-    /// let duration = Duration::new(5, 730023852);
-    /// assert_eq!(duration.as_millis(), 5730);
+    /// let micros = u64::MAX as u128 * 1_000;
+    /// assert_eq!(Duration::from_micros_u128(micros), Some(Duration::from_millis(u64::MAX)));
+    /// assert_eq!(Duration::from_micros_u128(u128::MAX), None);
     /// ```
-    #[stable(feature = "duration_as_u128", since = "1.33.0")]
-    #[rustc_const_stable(feature = "duration_as_u128", since = "1.33.0")]
+    ///
+    /// [`from_micros`]: Duration::from_micros
+    #[unstable(feature = "duration_from_u128", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_from_u128", issue = "none")]
     #[must_use]
     #[inline]
-    pub const fn as_millis(&self) -> u128 {
-        self.secs as u128 * MILLIS_PER_SEC as u128 + (self.nanos.0 / NANOS_PER_MILLI) as u128
+    pub const fn from_micros_u128(micros: u128) -> Option<Duration> {
+        match micros.checked_mul(NANOS_PER_MICRO as u128) {
+            Some(nanos) => Duration::from_nanos_u128(nanos),
+            None => None,
+        }
     }
 
-    /// Returns the total number of whole microseconds contained by this `Duration`.
+    /// Creates a new `Duration` from the specified number of nanoseconds as a
+    /// `u128`, returning [`None`] if it does not fit in a `Duration`.
+    ///
+    /// Unlike [`from_nanos`], this accepts every number of nanoseconds up to
+    /// [`Duration::MAX`], such as the result of [`as_nanos`].
     ///
     /// # Examples
     ///
     /// ```
+    /// #![feature(duration_from_u128)]
     /// use std::time::Duration;
     ///
-    /// let duration = Duration::new(5, 730023852);
-    /// assert_eq!(duration.as_micros(), 5730023);
+    /// let nanos = Duration::MAX.as_nanos();
+    /// assert_eq!(Duration::from_nanos_u128(nanos), Some(Duration::MAX));
+    /// assert_eq!(Duration::from_nanos_u128(nanos + 1), None);
     /// ```
-    #[stable(feature = "duration_as_u128", since = "1.33.0")]
-    #[rustc_const_stable(feature = "duration_as_u128", since = "1.33.0")]
+    ///
+    /// [`from_nanos`]: Duration::from_nanos
+    /// [`as_nanos`]: Duration::as_nanos
+    #[unstable(feature = "duration_from_u128", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_from_u128", issue = "none")]
     #[must_use]
     #[inline]
-    pub const fn as_micros(&self) -> u128 {
-        self.secs as u128 * MICROS_PER_SEC as u128 + (self.nanos.0 / NANOS_PER_MICRO) as u128
+    pub const fn from_nanos_u128(nanos: u128) -> Option<Duration> {
+        let secs = nanos / (NANOS_PER_SEC as u128);
+        if secs > u64::MAX as u128 {
+            return None;
+        }
+        Some(Duration::new(secs as u64, (nanos % (NANOS_PER_SEC as u128)) as u32))
     }
 
-    /// Returns the total number of nanoseconds contained by this `Duration`.
+    /// Returns the time elapsed from `epoch_nanos` to `now_nanos`, both given in
+    /// nanoseconds since some common epoch.
+    ///
+    /// This never panics: if `now_nanos` is before `epoch_nanos`, for example
+    /// because a clock was adjusted backwards, [`Duration::ZERO`] is returned,
+    /// and if the elapsed time does not fit in a `Duration`, [`Duration::MAX`]
+    /// is returned.
     ///
     /// # Examples
     ///
     /// ```
+    /// #![feature(duration_since_epoch)]
     /// use std::time::Duration;
     ///
-    /// let duration = Duration::new(5, 730023852);
-    /// assert_eq!(duration.as_nanos(), 5730023852);
+    /// let start = 1_700_000_000_000_000_000;
+    /// let now = 1_700_000_002_500_000_000;
+    /// assert_eq!(Duration::since_epoch(start, now), Duration::from_millis(2_500));
+    /// assert_eq!(Duration::since_epoch(now, start), Duration::ZERO);
     /// ```
-    #[stable(feature = "duration_as_u128", since = "1.33.0")]
-    #[rustc_const_stable(feature = "duration_as_u128", since = "1.33.0")]
+    #[unstable(feature = "duration_since_epoch", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_since_epoch", issue = "none")]
     #[must_use]
     #[inline]
-    pub const fn as_nanos(&self) -> u128 {
-        self.secs as u128 * NANOS_PER_SEC as u128 + self.nanos.0 as u128
+    pub const fn since_epoch(epoch_nanos: u128, now_nanos: u128) -> Duration {
+        let elapsed = now_nanos.saturating_sub(epoch_nanos);
+        match Duration::from_nanos_u128(elapsed) {
+            Some(duration) => duration,
+            None => Duration::MAX,
+        }
     }
 
-    /// Checked `Duration` addition. Computes `self + other`, returning [`None`]
-    /// if overflow occurred.
+    /// Creates a new `Duration` of `numerator / denominator` seconds, returning
+    /// [`None`] if `denominator` is zero.
     ///
-    /// # Examples
+    /// Only integer arithmetic is used, so unlike
+    /// `Duration::from_secs_f64(numerator as f64 / denominator as f64)` this is
+    /// not affected by floating-point rounding. The result is rounded down to
+    /// whole nanoseconds.
     ///
-    /// Basic usage:
+    /// # Examples
     ///
     /// ```
+    /// #![feature(duration_from_secs_rational)]
     /// use std::time::Duration;
     ///
-    /// assert_eq!(Duration::new(0, 0).checked_add(Duration::new(0, 1)), Some(Duration::new(0, 1)));
-    /// assert_eq!(Duration::new(1, 0).checked_add(Duration::new(u64::MAX, 0)), None);
+    /// assert_eq!(Duration::from_secs_rational(1, 3), Some(Duration::from_nanos(333_333_333)));
+    /// assert_eq!(Duration::from_secs_rational(1, 48_000), Some(Duration::from_nanos(20_833)));
+    /// assert_eq!(Duration::from_secs_rational(6, 4), Some(Duration::from_millis(1_500)));
+    /// assert_eq!(Duration::from_secs_rational(1, 0), None);
     /// ```
-    #[stable(feature = "duration_checked_ops", since = "1.16.0")]
-    #[must_use = "this returns the result of the operation, \
-                  without modifying the original"]
+    #[unstable(feature = "duration_from_secs_rational", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_from_secs_rational", issue = "none")]
+    #[doc(alias = "from_secs_f64_exact")]
+    #[must_use]
     #[inline]
-    #[rustc_const_stable(feature = "duration_consts_2", since = "1.58.0")]
-    pub const fn checked_add(self, rhs: Duration) -> Option<Duration> {
-        if let Some(mut secs) = self.secs.checked_add(rhs.secs) {
-            let mut nanos = self.nanos.0 + rhs.nanos.0;
-            if nanos >= NANOS_PER_SEC {
-                nanos -= NANOS_PER_SEC;
-                if let Some(new_secs) = secs.checked_add(1) {
-                    secs = new_secs;
-                } else {
-                    return None;
-                }
-            }
-            debug_assert!(nanos < NANOS_PER_SEC);
-            Some(Duration::new(secs, nanos))
-        } else {
-            None
+    pub const fn from_secs_rational(numerator: u64, denominator: u64) -> Option<Duration> {
+        if denominator == 0 {
+            return None;
         }
+        let secs = numerator / denominator;
+        let remainder = (numerator % denominator) as u128;
+        // `remainder < denominator`, so this is less than one second.
+        let nanos = (remainder * NANOS_PER_SEC as u128 / denominator as u128) as u32;
+        Some(Duration::new(secs, nanos))
     }
 
-    /// Saturating `Duration` addition. Computes `self + other`, returning [`Duration::MAX`]
-    /// if overflow occurred.
+    /// Creates a new `Duration` from an SMPTE timecode `HH:MM:SS:FF` whose
+    /// fields are encoded in binary-coded decimal, as sent by capture hardware.
+    ///
+    /// Each byte holds the tens digit of its field in the upper four bits and
+    /// the units digit in the lower four bits, so that `0x59` is 59. The frame
+    /// number `frames` is converted to a fraction of a second using the frame
+    /// rate `fps`, rounded down to whole nanoseconds.
+    ///
+    /// Returns [`None`] if a byte is not valid binary-coded decimal, if a field
+    /// is out of range (hours of 24 or more, minutes or seconds of 60 or more,
+    /// or a frame number not less than `fps`), or if `fps` is zero.
     ///
     /// # Examples
     ///
     /// ```
-    /// #![feature(duration_constants)]
+    /// #![feature(duration_from_bcd_time)]
     /// use std::time::Duration;
     ///
-    /// assert_eq!(Duration::new(0, 0).saturating_add(Duration::new(0, 1)), Duration::new(0, 1));
-    /// assert_eq!(Duration::new(1, 0).saturating_add(Duration::new(u64::MAX, 0)), Duration::MAX);
+    /// // 01:02:03:12 at 25 frames per second.
+    /// let d = Duration::from_bcd_time(0x01, 0x02, 0x03, 0x12, 25);
+    /// assert_eq!(d, Some(Duration::new(3_723, 480_000_000)));
+    ///
+    /// assert_eq!(Duration::from_bcd_time(0x00, 0x60, 0x00, 0x00, 25), None);
+    /// assert_eq!(Duration::from_bcd_time(0x00, 0x0a, 0x00, 0x00, 25), None);
+    /// assert_eq!(Duration::from_bcd_time(0x00, 0x00, 0x00, 0x25, 25), None);
     /// ```
-    #[stable(feature = "duration_saturating_ops", since = "1.53.0")]
-    #[must_use = "this returns the result of the operation, \
-                  without modifying the original"]
+    #[unstable(feature = "duration_from_bcd_time", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_from_bcd_time", issue = "none")]
+    #[must_use]
     #[inline]
-    #[rustc_const_stable(feature = "duration_consts_2", since = "1.58.0")]
-    pub const fn saturating_add(self, rhs: Duration) -> Duration {
-        match self.checked_add(rhs) {
-            Some(res) => res,
-            None => Duration::MAX,
+    pub const fn from_bcd_time(
+        hours: u8,
+        minutes: u8,
+        seconds: u8,
+        frames: u8,
+        fps: u32,
+    ) -> Option<Duration> {
+        let (hours, minutes, seconds, frames) = match (
+            bcd_to_binary(hours),
+            bcd_to_binary(minutes),
+            bcd_to_binary(seconds),
+            bcd_to_binary(frames),
+        ) {
+            (Some(hours), Some(minutes), Some(seconds), Some(frames)) => {
+                (hours as u64, minutes as u64, seconds as u64, frames as u32)
+            }
+            _ => return None,
+        };
+        if hours >= HOURS_PER_DAY
+            || minutes >= MINS_PER_HOUR
+            || seconds >= SECS_PER_MINUTE
+            || frames >= fps
+        {
+            return None;
         }
+        let secs = (hours * MINS_PER_HOUR + minutes) * SECS_PER_MINUTE + seconds;
+        // `frames < fps`, so this is less than one second.
+        let nanos = (frames as u64 * NANOS_PER_SEC as u64 / fps as u64) as u32;
+        Some(Duration::new(secs, nanos))
     }
 
-    /// Checked `Duration` subtraction. Computes `self - other`, returning [`None`]
-    /// if the result would be negative or if overflow occurred.
+    /// Splits this `Duration` into the hours, minutes, seconds and frame
+    /// number of an SMPTE timecode `HH:MM:SS:FF` at `fps` frames per second.
     ///
-    /// # Examples
+    /// This is the inverse of [`Duration::from_bcd_time`], except that the
+    /// fields are returned as plain integers rather than in binary-coded
+    /// decimal, ready for display. Like a timecode, the hours wrap around at
+    /// midnight, so a `Duration` of 24 hours or more gives the time of day
+    /// that it ends at.
     ///
-    /// Basic usage:
+    /// The frame number is rounded down, except that a `Duration` less than a
+    /// nanosecond short of the start of a frame counts as being in that frame.
+    /// [`Duration::from_bcd_time`] rounds the start of a frame down to whole
+    /// nanoseconds, and this makes sure that its result maps to the same frame.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fps` is zero or greater than 256, as the frame number then
+    /// would not fit in a `u8`.
+    ///
+    /// # Examples
     ///
     /// ```
+    /// #![feature(duration_smpte_timecode)]
     /// use std::time::Duration;
     ///
-    /// assert_eq!(Duration::new(0, 1).checked_sub(Duration::new(0, 0)), Some(Duration::new(0, 1)));
-    /// assert_eq!(Duration::new(0, 0).checked_sub(Duration::new(0, 1)), None);
+    /// let d = Duration::new(3_723, 480_000_000);
+    /// assert_eq!(d.as_smpte_timecode(25), (1, 2, 3, 12));
+    ///
+    /// // 25 hours after midnight is one o'clock.
+    /// assert_eq!(Duration::from_secs(25 * 3_600).as_smpte_timecode(25), (1, 0, 0, 0));
     /// ```
-    #[stable(feature = "duration_checked_ops", since = "1.16.0")]
-    #[must_use = "this returns the result of the operation, \
-                  without modifying the original"]
+    #[unstable(feature = "duration_smpte_timecode", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_smpte_timecode", issue = "none")]
+    #[must_use]
     #[inline]
-    #[rustc_const_stable(feature = "duration_consts_2", since = "1.58.0")]
-    pub const fn checked_sub(self, rhs: Duration) -> Option<Duration> {
-        if let Some(mut secs) = self.secs.checked_sub(rhs.secs) {
-            let nanos = if self.nanos.0 >= rhs.nanos.0 {
-                self.nanos.0 - rhs.nanos.0
-            } else if let Some(sub_secs) = secs.checked_sub(1) {
-                secs = sub_secs;
-                self.nanos.0 + NANOS_PER_SEC - rhs.nanos.0
-            } else {
-                return None;
-            };
-            debug_assert!(nanos < NANOS_PER_SEC);
-            Some(Duration::new(secs, nanos))
-        } else {
-            None
+    #[track_caller]
+    pub const fn as_smpte_timecode(self, fps: u32) -> (u8, u8, u8, u8) {
+        if fps == 0 || fps > u8::MAX as u32 + 1 {
+            panic!("invalid frame rate in Duration::as_smpte_timecode");
         }
+        let secs = self.secs % (SECS_PER_MINUTE * MINS_PER_HOUR * HOURS_PER_DAY);
+        let hours = secs / (SECS_PER_MINUTE * MINS_PER_HOUR);
+        let minutes = secs / SECS_PER_MINUTE % MINS_PER_HOUR;
+        let seconds = secs % SECS_PER_MINUTE;
+        // Adding `fps - 1` before dividing moves a `Duration` that is less than
+        // a nanosecond short of a frame boundary into the next frame. As
+        // `self.nanos < NANOS_PER_SEC`, the result is still less than `fps`.
+        let frames = (self.nanos.0 as u64 * fps as u64 + (fps as u64 - 1)) / NANOS_PER_SEC as u64;
+        (hours as u8, minutes as u8, seconds as u8, frames as u8)
     }
 
-    /// Saturating `Duration` subtraction. Computes `self - other`, returning [`Duration::ZERO`]
-    /// if the result would be negative or if overflow occurred.
+    /// Creates a new `Duration` from the specified number of audio CD frames.
+    ///
+    /// Audio CDs (CD-DA) are addressed in frames, or sectors, of which there
+    /// are 75 per second. The result is computed with integer arithmetic and
+    /// rounded down to whole nanoseconds.
     ///
     /// # Examples
     ///
     /// ```
+    /// #![feature(duration_cdda)]
     /// use std::time::Duration;
     ///
-    /// assert_eq!(Duration::new(0, 1).saturating_sub(Duration::new(0, 0)), Duration::new(0, 1));
-    /// assert_eq!(Duration::new(0, 0).saturating_sub(Duration::new(0, 1)), Duration::ZERO);
+    /// assert_eq!(Duration::from_cdda_frames(150), Duration::from_secs(2));
+    /// assert_eq!(Duration::from_cdda_frames(1), Duration::from_nanos(13_333_333));
     /// ```
-    #[stable(feature = "duration_saturating_ops", since = "1.53.0")]
-    #[must_use = "this returns the result of the operation, \
-                  without modifying the original"]
+    #[unstable(feature = "duration_cdda", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_cdda", issue = "none")]
+    #[must_use]
     #[inline]
-    #[rustc_const_stable(feature = "duration_consts_2", since = "1.58.0")]
-    pub const fn saturating_sub(self, rhs: Duration) -> Duration {
-        match self.checked_sub(rhs) {
-            Some(res) => res,
+    pub const fn from_cdda_frames(frames: u64) -> Duration {
+        let secs = frames / CDDA_FRAMES_PER_SEC;
+        // `frames % CDDA_FRAMES_PER_SEC < CDDA_FRAMES_PER_SEC`, so this is less
+        // than one second.
+        let nanos =
+            (frames % CDDA_FRAMES_PER_SEC * NANOS_PER_SEC as u64 / CDDA_FRAMES_PER_SEC) as u32;
+        Duration::new(secs, nanos)
+    }
+
+    /// Returns the number of whole audio CD frames contained by this `Duration`.
+    ///
+    /// There are 75 frames per second. The result is rounded down, except that
+    /// a `Duration` less than a nanosecond short of the start of a frame counts
+    /// as being in that frame, so that the result of
+    /// [`Duration::from_cdda_frames`] maps back to the same number of frames.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of frames overflows a `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_cdda)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::from_secs(2).to_cdda_frames(), 150);
+    /// assert_eq!(Duration::from_millis(20).to_cdda_frames(), 1);
+    /// assert_eq!(Duration::from_cdda_frames(1).to_cdda_frames(), 1);
+    /// ```
+    #[unstable(feature = "duration_cdda", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_cdda", issue = "none")]
+    #[must_use]
+    #[inline]
+    #[track_caller]
+    pub const fn to_cdda_frames(self) -> u64 {
+        // See `as_smpte_timecode` for why `CDDA_FRAMES_PER_SEC - 1` is added.
+        let subsec_frames = (self.nanos.0 as u64 * CDDA_FRAMES_PER_SEC + (CDDA_FRAMES_PER_SEC - 1))
+            / NANOS_PER_SEC as u64;
+        match self.secs.checked_mul(CDDA_FRAMES_PER_SEC) {
+            Some(frames) => match frames.checked_add(subsec_frames) {
+                Some(frames) => frames,
+                None => panic!("overflow in Duration::to_cdda_frames"),
+            },
+            None => panic!("overflow in Duration::to_cdda_frames"),
+        }
+    }
+
+    /// Returns true if this `Duration` spans no time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// assert!(Duration::ZERO.is_zero());
+    /// assert!(Duration::new(0, 0).is_zero());
+    /// assert!(Duration::from_nanos(0).is_zero());
+    /// assert!(Duration::from_secs(0).is_zero());
+    ///
+    /// assert!(!Duration::new(1, 1).is_zero());
+    /// assert!(!Duration::from_nanos(1).is_zero());
+    /// assert!(!Duration::from_secs(1).is_zero());
+    /// ```
+    #[must_use]
+    #[stable(feature = "duration_zero", since = "1.53.0")]
+    #[rustc_const_stable(feature = "duration_zero", since = "1.53.0")]
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.secs == 0 && self.nanos.0 == 0
+    }
+
+    /// Compares `self` and `other` in a `const` context.
+    ///
+    /// This gives the same result as [`Ord::cmp`], which can not be called in
+    /// constants yet. The whole seconds are compared first and the nanoseconds
+    /// only if the whole seconds are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_const_cmp)]
+    /// use std::cmp::Ordering;
+    /// use std::time::Duration;
+    ///
+    /// const MIN_TIMEOUT: Duration = Duration::from_millis(100);
+    /// const MAX_TIMEOUT: Duration = Duration::from_secs(30);
+    /// const _: () = assert!(matches!(MIN_TIMEOUT.strict_ordering(&MAX_TIMEOUT), Ordering::Less));
+    ///
+    /// assert_eq!(MAX_TIMEOUT.strict_ordering(&MIN_TIMEOUT), Ordering::Greater);
+    /// assert_eq!(MIN_TIMEOUT.strict_ordering(&MIN_TIMEOUT), Ordering::Equal);
+    /// ```
+    #[unstable(feature = "duration_const_cmp", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_const_cmp", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn strict_ordering(self, other: &Duration) -> Ordering {
+        if self.secs < other.secs {
+            Ordering::Less
+        } else if self.secs > other.secs {
+            Ordering::Greater
+        } else if self.nanos.0 < other.nanos.0 {
+            Ordering::Less
+        } else if self.nanos.0 > other.nanos.0 {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    /// Returns the shorter of `a` and `b` in a `const` context.
+    ///
+    /// This gives the same result as [`Ord::min`], which can not be called in
+    /// constants yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_const_cmp)]
+    /// use std::time::Duration;
+    ///
+    /// const USER_TIMEOUT: Duration = Duration::from_secs(90);
+    /// const HARD_LIMIT: Duration = Duration::from_secs(60);
+    /// const TIMEOUT: Duration = Duration::const_min(USER_TIMEOUT, HARD_LIMIT);
+    /// assert_eq!(TIMEOUT, HARD_LIMIT);
+    /// ```
+    #[unstable(feature = "duration_const_cmp", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_const_cmp", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn const_min(a: Duration, b: Duration) -> Duration {
+        match a.strict_ordering(&b) {
+            Ordering::Greater => b,
+            Ordering::Less | Ordering::Equal => a,
+        }
+    }
+
+    /// Returns the longer of `a` and `b` in a `const` context.
+    ///
+    /// This gives the same result as [`Ord::max`], which can not be called in
+    /// constants yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_const_cmp)]
+    /// use std::time::Duration;
+    ///
+    /// const USER_TIMEOUT: Duration = Duration::from_millis(10);
+    /// const MIN_TIMEOUT: Duration = Duration::from_millis(100);
+    /// const TIMEOUT: Duration = Duration::const_max(USER_TIMEOUT, MIN_TIMEOUT);
+    /// assert_eq!(TIMEOUT, MIN_TIMEOUT);
+    /// ```
+    #[unstable(feature = "duration_const_cmp", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_const_cmp", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn const_max(a: Duration, b: Duration) -> Duration {
+        match a.strict_ordering(&b) {
+            Ordering::Greater => a,
+            Ordering::Less | Ordering::Equal => b,
+        }
+    }
+
+    /// Interprets this `Duration` as a timeout, where [`Duration::MAX`] means
+    /// "wait forever".
+    ///
+    /// Returns [`None`] for [`Duration::MAX`] and `Some(self)` otherwise. This is
+    /// the inverse of [`Duration::from_timeout`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_timeout)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::MAX.timeout(), None);
+    /// assert_eq!(Duration::from_secs(5).timeout(), Some(Duration::from_secs(5)));
+    /// assert_eq!(Duration::ZERO.timeout(), Some(Duration::ZERO));
+    /// ```
+    #[unstable(feature = "duration_timeout", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_timeout", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn timeout(self) -> Option<Duration> {
+        if self.secs == Duration::MAX.secs && self.nanos.0 == Duration::MAX.nanos.0 {
+            None
+        } else {
+            Some(self)
+        }
+    }
+
+    /// Creates a `Duration` from an optional timeout, where [`None`] means "wait
+    /// forever".
+    ///
+    /// Returns [`Duration::MAX`] for [`None`] and the contained `Duration`
+    /// otherwise. This is the inverse of [`Duration::timeout`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_timeout)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::from_timeout(None), Duration::MAX);
+    /// assert_eq!(Duration::from_timeout(Some(Duration::from_secs(5))), Duration::from_secs(5));
+    /// ```
+    #[unstable(feature = "duration_timeout", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_timeout", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn from_timeout(timeout: Option<Duration>) -> Duration {
+        match timeout {
+            Some(duration) => duration,
+            None => Duration::MAX,
+        }
+    }
+
+    /// Returns the number of _whole_ seconds contained by this `Duration`.
+    ///
+    /// The returned value does not include the fractional (nanosecond) part of the
+    /// duration, which can be obtained using [`subsec_nanos`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let duration = Duration::new(5, 730023852);
+    /// assert_eq!(duration.as_secs(), 5);
+    /// ```
+    ///
+    /// To determine the total number of seconds represented by the `Duration`
+    /// including the fractional part, use [`as_secs_f64`] or [`as_secs_f32`]
+    ///
+    /// [`as_secs_f64`]: Duration::as_secs_f64
+    /// [`as_secs_f32`]: Duration::as_secs_f32
+    /// [`subsec_nanos`]: Duration::subsec_nanos
+    #[stable(feature = "duration", since = "1.3.0")]
+    #[rustc_const_stable(feature = "duration_consts", since = "1.32.0")]
+    #[must_use]
+    #[inline]
+    pub const fn as_secs(&self) -> u64 {
+        self.secs
+    }
+
+    /// Returns the number of seconds contained by this `Duration`, rounded up to
+    /// a whole number.
+    ///
+    /// Unlike [`as_secs`], which discards the fractional part, any nonzero
+    /// fractional part makes the result one larger. If that would overflow, the
+    /// result is `u64::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_rounding)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::new(1, 500_000_000).as_secs_ceiling(), 2);
+    /// assert_eq!(Duration::new(2, 0).as_secs_ceiling(), 2);
+    /// assert_eq!(Duration::new(0, 1).as_secs_ceiling(), 1);
+    /// assert_eq!(Duration::MAX.as_secs_ceiling(), u64::MAX);
+    /// ```
+    ///
+    /// [`as_secs`]: Duration::as_secs
+    #[unstable(feature = "duration_rounding", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_rounding", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn as_secs_ceiling(&self) -> u64 {
+        if self.nanos.0 > 0 { self.secs.saturating_add(1) } else { self.secs }
+    }
+
+    /// Returns the number of seconds contained by this `Duration`, rounded to
+    /// the nearest whole number.
+    ///
+    /// A fractional part of exactly half a second is rounded up. If rounding up
+    /// would overflow, the result is `u64::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_rounding)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::new(1, 499_999_999).as_secs_round(), 1);
+    /// assert_eq!(Duration::new(1, 500_000_000).as_secs_round(), 2);
+    /// assert_eq!(Duration::new(2, 0).as_secs_round(), 2);
+    /// assert_eq!(Duration::MAX.as_secs_round(), u64::MAX);
+    /// ```
+    #[unstable(feature = "duration_rounding", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_rounding", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn as_secs_round(&self) -> u64 {
+        if self.nanos.0 >= NANOS_PER_SEC / 2 { self.secs.saturating_add(1) } else { self.secs }
+    }
+
+    /// Returns the number of _whole_ seconds contained by this `Duration` as a
+    /// `u128`.
+    ///
+    /// This is the same value as [`as_secs`], widened so that the seconds of
+    /// many durations can be added up without overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_as_secs_u128)]
+    /// use std::time::Duration;
+    ///
+    /// let durations = [Duration::MAX, Duration::MAX];
+    /// let total_secs: u128 = durations.iter().map(|d| d.as_secs_u128()).sum();
+    /// assert_eq!(total_secs, 2 * u64::MAX as u128);
+    /// ```
+    ///
+    /// [`as_secs`]: Duration::as_secs
+    #[unstable(feature = "duration_as_secs_u128", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_as_secs_u128", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn as_secs_u128(&self) -> u128 {
+        self.secs as u128
+    }
+
+    /// Creates a new `Duration` from a signed number of whole seconds, returning
+    /// [`None`] if `secs` is negative.
+    ///
+    /// Many system interfaces, such as those taking a `time_t` timeout, use
+    /// signed seconds. [`Duration::to_secs_i64`] converts in the other direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_secs_i64)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::from_secs_i64(5), Some(Duration::from_secs(5)));
+    /// assert_eq!(Duration::from_secs_i64(-5), None);
+    /// ```
+    #[unstable(feature = "duration_secs_i64", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_secs_i64", issue = "none")]
+    #[must_use]
+    #[inline]
+    #[doc(alias = "time_t")]
+    pub const fn from_secs_i64(secs: i64) -> Option<Duration> {
+        if secs >= 0 { Some(Duration::from_secs(secs as u64)) } else { None }
+    }
+
+    /// Returns the number of _whole_ seconds contained by this `Duration` as an
+    /// `i64`, or [`None`] if it does not fit.
+    ///
+    /// The fractional part is discarded, as with [`as_secs`]. This is the inverse
+    /// of [`Duration::from_secs_i64`] for durations with no fractional part.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_secs_i64)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::new(5, 730_023_852).to_secs_i64(), Some(5));
+    /// assert_eq!(Duration::MAX.to_secs_i64(), None);
+    /// ```
+    ///
+    /// [`as_secs`]: Duration::as_secs
+    #[unstable(feature = "duration_secs_i64", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_secs_i64", issue = "none")]
+    #[must_use]
+    #[inline]
+    #[doc(alias = "time_t")]
+    pub const fn to_secs_i64(&self) -> Option<i64> {
+        if self.secs <= i64::MAX as u64 { Some(self.secs as i64) } else { None }
+    }
+
+    /// Returns the fractional part of this `Duration`, in whole milliseconds.
+    ///
+    /// This method does **not** return the length of the duration when
+    /// represented by milliseconds. The returned number always represents a
+    /// fractional portion of a second (i.e., it is less than one thousand).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let duration = Duration::from_millis(5432);
+    /// assert_eq!(duration.as_secs(), 5);
+    /// assert_eq!(duration.subsec_millis(), 432);
+    /// ```
+    #[stable(feature = "duration_extras", since = "1.27.0")]
+    #[rustc_const_stable(feature = "duration_consts", since = "1.32.0")]
+    #[must_use]
+    #[inline]
+    pub const fn subsec_millis(&self) -> u32 {
+        self.nanos.0 / NANOS_PER_MILLI
+    }
+
+    /// Returns the fractional part of this `Duration`, in whole microseconds.
+    ///
+    /// This method does **not** return the length of the duration when
+    /// represented by microseconds. The returned number always represents a
+    /// fractional portion of a second (i.e., it is less than one million).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let duration = Duration::from_micros(1_234_567);
+    /// assert_eq!(duration.as_secs(), 1);
+    /// assert_eq!(duration.subsec_micros(), 234_567);
+    /// ```
+    #[stable(feature = "duration_extras", since = "1.27.0")]
+    #[rustc_const_stable(feature = "duration_consts", since = "1.32.0")]
+    #[must_use]
+    #[inline]
+    pub const fn subsec_micros(&self) -> u32 {
+        self.nanos.0 / NANOS_PER_MICRO
+    }
+
+    /// Returns the fractional part of this `Duration`, in nanoseconds.
+    ///
+    /// This method does **not** return the length of the duration when
+    /// represented by nanoseconds. The returned number always represents a
+    /// fractional portion of a second (i.e., it is less than one billion).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let duration = Duration::from_millis(5010);
+    /// assert_eq!(duration.as_secs(), 5);
+    /// assert_eq!(duration.subsec_nanos(), 10_000_000);
+    /// ```
+    #[stable(feature = "duration", since = "1.3.0")]
+    #[rustc_const_stable(feature = "duration_consts", since = "1.32.0")]
+    #[must_use]
+    #[inline]
+    pub const fn subsec_nanos(&self) -> u32 {
+        self.nanos.0
+    }
+
+    /// Returns the total number of whole milliseconds contained by this `Duration`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let duration = Duration::new(5, 730023852);
+    /// assert_eq!(duration.as_millis(), 5730);
+    /// ```
+    #[stable(feature = "duration_as_u128", since = "1.33.0")]
+    #[rustc_const_stable(feature = "duration_as_u128", since = "1.33.0")]
+    #[must_use]
+    #[inline]
+    pub const fn as_millis(&self) -> u128 {
+        self.secs as u128 * MILLIS_PER_SEC as u128 + (self.nanos.0 / NANOS_PER_MILLI) as u128
+    }
+
+    /// Returns the number of milliseconds contained by this `Duration`, rounded
+    /// up to a whole number.
+    ///
+    /// Unlike [`as_millis`], which discards any fraction of a millisecond, the
+    /// result is never less than the duration itself, which is useful for
+    /// timeouts that must not expire early.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_rounding)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::from_micros(1_500).as_millis_ceiling(), 2);
+    /// assert_eq!(Duration::from_millis(2).as_millis_ceiling(), 2);
+    /// assert_eq!(Duration::from_nanos(1).as_millis_ceiling(), 1);
+    /// ```
+    ///
+    /// [`as_millis`]: Duration::as_millis
+    #[unstable(feature = "duration_rounding", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_rounding", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn as_millis_ceiling(&self) -> u128 {
+        self.as_millis() + (self.nanos.0 % NANOS_PER_MILLI > 0) as u128
+    }
+
+    /// Returns the total number of whole microseconds contained by this `Duration`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let duration = Duration::new(5, 730023852);
+    /// assert_eq!(duration.as_micros(), 5730023);
+    /// ```
+    #[stable(feature = "duration_as_u128", since = "1.33.0")]
+    #[rustc_const_stable(feature = "duration_as_u128", since = "1.33.0")]
+    #[must_use]
+    #[inline]
+    pub const fn as_micros(&self) -> u128 {
+        self.secs as u128 * MICROS_PER_SEC as u128 + (self.nanos.0 / NANOS_PER_MICRO) as u128
+    }
+
+    /// Returns the total number of nanoseconds contained by this `Duration`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let duration = Duration::new(5, 730023852);
+    /// assert_eq!(duration.as_nanos(), 5730023852);
+    /// ```
+    #[stable(feature = "duration_as_u128", since = "1.33.0")]
+    #[rustc_const_stable(feature = "duration_as_u128", since = "1.33.0")]
+    #[must_use]
+    #[inline]
+    pub const fn as_nanos(&self) -> u128 {
+        self.secs as u128 * NANOS_PER_SEC as u128 + self.nanos.0 as u128
+    }
+
+    /// Returns the total number of nanoseconds contained by this `Duration` as a
+    /// `u64`, or [`None`] if it does not fit.
+    ///
+    /// Unlike `as_nanos() as u64`, this does not silently truncate durations
+    /// longer than [`u64::MAX`] nanoseconds, about 584 years.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_total_nanos_checked)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::new(5, 730_023_852).total_nanos_checked_u64(), Some(5_730_023_852));
+    /// assert_eq!(Duration::MAX.total_nanos_checked_u64(), None);
+    /// ```
+    #[unstable(feature = "duration_total_nanos_checked", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_total_nanos_checked", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn total_nanos_checked_u64(&self) -> Option<u64> {
+        let nanos = self.as_nanos();
+        if nanos <= u64::MAX as u128 { Some(nanos as u64) } else { None }
+    }
+
+    /// Returns the total number of nanoseconds contained by this `Duration` as an
+    /// `i64`, or [`None`] if it does not fit.
+    ///
+    /// This is the representation of Go's `time.Duration`. Unlike
+    /// `as_nanos() as i64`, this does not silently wrap durations longer than
+    /// [`i64::MAX`] nanoseconds, about 292 years. [`Duration::from_go_duration`]
+    /// converts in the other direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_total_nanos_checked)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::new(5, 730_023_852).total_nanos_checked_i64(), Some(5_730_023_852));
+    /// assert_eq!(Duration::from_secs(300 * 365 * 24 * 60 * 60).total_nanos_checked_i64(), None);
+    /// ```
+    #[unstable(feature = "duration_total_nanos_checked", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_total_nanos_checked", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn total_nanos_checked_i64(&self) -> Option<i64> {
+        let nanos = self.as_nanos();
+        if nanos <= i64::MAX as u128 { Some(nanos as i64) } else { None }
+    }
+
+    /// Creates a new `Duration` from a Go `time.Duration`, a signed number of
+    /// nanoseconds, returning [`None`] if `nanos` is negative.
+    ///
+    /// [`Duration::to_go_duration`] converts in the other direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_go)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::from_go_duration(1_500_000_000), Some(Duration::from_millis(1_500)));
+    /// assert_eq!(Duration::from_go_duration(-1), None);
+    /// ```
+    #[unstable(feature = "duration_go", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_go", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn from_go_duration(nanos: i64) -> Option<Duration> {
+        if nanos >= 0 { Some(Duration::from_nanos(nanos as u64)) } else { None }
+    }
+
+    /// Converts this `Duration` to a Go `time.Duration`, a signed number of
+    /// nanoseconds, returning [`None`] if it is longer than [`i64::MAX`]
+    /// nanoseconds.
+    ///
+    /// This is the same as [`Duration::total_nanos_checked_i64`].
+    /// [`Duration::from_go_duration`] converts in the other direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_go)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::from_millis(1_500).to_go_duration(), Some(1_500_000_000));
+    /// assert_eq!(Duration::MAX.to_go_duration(), None);
+    /// ```
+    #[unstable(feature = "duration_go", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_go", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn to_go_duration(self) -> Option<i64> {
+        self.total_nanos_checked_i64()
+    }
+
+    /// Creates a new `Duration` from the fields of a Python `datetime.timedelta`,
+    /// returning [`None`] if any of them is negative.
+    ///
+    /// A `timedelta` is normalized so that `0 <= seconds < 86400` and
+    /// `0 <= microseconds < 1000000`, with only `days` negative for a negative
+    /// `timedelta`, but fields that are not normalized are simply added up.
+    /// [`Duration::to_python_timedelta`] converts in the other direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_python)]
+    /// use std::time::Duration;
+    ///
+    /// let d = Duration::from_python_timedelta(1, 30, 500_000);
+    /// assert_eq!(d, Some(Duration::new(86_430, 500_000_000)));
+    ///
+    /// // `datetime.timedelta(seconds=-1)` is stored as `days=-1, seconds=86399`.
+    /// assert_eq!(Duration::from_python_timedelta(-1, 86_399, 0), None);
+    /// ```
+    #[unstable(feature = "duration_python", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_python", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn from_python_timedelta(
+        days: i32,
+        seconds: i32,
+        microseconds: i32,
+    ) -> Option<Duration> {
+        if days < 0 || seconds < 0 || microseconds < 0 {
+            return None;
+        }
+        // Even with every field at `i32::MAX`, this is far below `u64::MAX`.
+        let secs = days as u64 * SECS_PER_MINUTE * MINS_PER_HOUR * HOURS_PER_DAY
+            + seconds as u64
+            + microseconds as u64 / MICROS_PER_SEC;
+        let nanos = (microseconds as u64 % MICROS_PER_SEC) as u32 * NANOS_PER_MICRO;
+        Some(Duration::new(secs, nanos))
+    }
+
+    /// Returns the `days`, `seconds` and `microseconds` fields of the Python
+    /// `datetime.timedelta` that represents this `Duration`.
+    ///
+    /// The fields are normalized like those of a `timedelta`, so that
+    /// `0 <= seconds < 86400` and `0 <= microseconds < 1000000`. A `timedelta`
+    /// has a resolution of one microsecond, so the nanoseconds are rounded down
+    /// to whole microseconds. Python limits `days` to 999999999, which a long
+    /// `Duration` can exceed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_python)]
+    /// use std::time::Duration;
+    ///
+    /// let d = Duration::new(86_430, 500_000_999);
+    /// assert_eq!(d.to_python_timedelta(), (1, 30, 500_000));
+    /// ```
+    #[unstable(feature = "duration_python", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_python", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn to_python_timedelta(&self) -> (i64, i32, i32) {
+        let secs_per_day = SECS_PER_MINUTE * MINS_PER_HOUR * HOURS_PER_DAY;
+        // `u64::MAX / secs_per_day` fits in an `i64`.
+        let days = (self.secs / secs_per_day) as i64;
+        let seconds = (self.secs % secs_per_day) as i32;
+        let microseconds = (self.nanos.0 / NANOS_PER_MICRO) as i32;
+        (days, seconds, microseconds)
+    }
+
+    /// Checked `Duration` addition. Computes `self + other`, returning [`None`]
+    /// if overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::new(0, 0).checked_add(Duration::new(0, 1)), Some(Duration::new(0, 1)));
+    /// assert_eq!(Duration::new(1, 0).checked_add(Duration::new(u64::MAX, 0)), None);
+    /// ```
+    #[stable(feature = "duration_checked_ops", since = "1.16.0")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    #[rustc_const_stable(feature = "duration_consts_2", since = "1.58.0")]
+    pub const fn checked_add(self, rhs: Duration) -> Option<Duration> {
+        if let Some(mut secs) = self.secs.checked_add(rhs.secs) {
+            let mut nanos = self.nanos.0 + rhs.nanos.0;
+            if nanos >= NANOS_PER_SEC {
+                nanos -= NANOS_PER_SEC;
+                if let Some(new_secs) = secs.checked_add(1) {
+                    secs = new_secs;
+                } else {
+                    return None;
+                }
+            }
+            debug_assert!(nanos < NANOS_PER_SEC);
+            Some(Duration::new(secs, nanos))
+        } else {
+            None
+        }
+    }
+
+    /// Checked addition of a number of microseconds. Computes
+    /// `self + Duration::from_micros_u128(micros)`, returning [`None`] if
+    /// overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_checked_add_micros)]
+    /// use std::time::Duration;
+    ///
+    /// let d = Duration::new(1, 999_999_000);
+    /// assert_eq!(d.checked_add_micros(2), Some(Duration::new(2, 1_000)));
+    /// assert_eq!(d.checked_add_micros(u64::MAX as u128 * 1_000_000), None);
+    /// assert_eq!(d.checked_add_micros(u128::MAX), None);
+    /// ```
+    #[unstable(feature = "duration_checked_add_micros", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_checked_add_micros", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_add_micros(self, micros: u128) -> Option<Duration> {
+        match Duration::from_micros_u128(micros) {
+            Some(rhs) => self.checked_add(rhs),
+            None => None,
+        }
+    }
+
+    /// Checked addition of a number of minutes. Computes
+    /// `self + Duration::from_secs(minutes * 60)`, returning [`None`] if
+    /// overflow occurred, either in the conversion to seconds or in the addition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_add_units_checked)]
+    /// use std::time::Duration;
+    ///
+    /// let d = Duration::new(1, 5);
+    /// assert_eq!(d.add_minutes_checked(2), Some(Duration::new(121, 5)));
+    /// assert_eq!(d.add_minutes_checked(u64::MAX / 60 + 1), None);
+    /// assert_eq!(Duration::MAX.add_minutes_checked(1), None);
+    /// ```
+    #[unstable(feature = "duration_add_units_checked", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_add_units_checked", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn add_minutes_checked(self, minutes: u64) -> Option<Duration> {
+        self.checked_add_scaled_secs(minutes, SECS_PER_MINUTE)
+    }
+
+    /// Checked addition of a number of hours. Computes
+    /// `self + Duration::from_secs(hours * 3_600)`, returning [`None`] if
+    /// overflow occurred, either in the conversion to seconds or in the addition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_add_units_checked)]
+    /// use std::time::Duration;
+    ///
+    /// let d = Duration::new(1, 5);
+    /// assert_eq!(d.add_hours_checked(2), Some(Duration::new(7_201, 5)));
+    /// assert_eq!(d.add_hours_checked(u64::MAX / 3_600 + 1), None);
+    /// assert_eq!(Duration::MAX.add_hours_checked(1), None);
+    /// ```
+    #[unstable(feature = "duration_add_units_checked", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_add_units_checked", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn add_hours_checked(self, hours: u64) -> Option<Duration> {
+        self.checked_add_scaled_secs(hours, SECS_PER_MINUTE * MINS_PER_HOUR)
+    }
+
+    /// Checked addition of a number of days. Computes
+    /// `self + Duration::from_secs(days * 86_400)`, returning [`None`] if
+    /// overflow occurred, either in the conversion to seconds or in the addition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_add_units_checked)]
+    /// use std::time::Duration;
+    ///
+    /// let d = Duration::new(1, 5);
+    /// assert_eq!(d.add_days_checked(2), Some(Duration::new(172_801, 5)));
+    /// assert_eq!(d.add_days_checked(u64::MAX / 86_400 + 1), None);
+    /// assert_eq!(Duration::MAX.add_days_checked(1), None);
+    /// ```
+    #[unstable(feature = "duration_add_units_checked", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_add_units_checked", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn add_days_checked(self, days: u64) -> Option<Duration> {
+        self.checked_add_scaled_secs(days, SECS_PER_MINUTE * MINS_PER_HOUR * HOURS_PER_DAY)
+    }
+
+    /// Computes `self + Duration::from_secs(count * secs_per_unit)`, returning
+    /// [`None`] if either step overflows.
+    #[inline]
+    const fn checked_add_scaled_secs(self, count: u64, secs_per_unit: u64) -> Option<Duration> {
+        let extra_secs = match count.checked_mul(secs_per_unit) {
+            Some(extra_secs) => extra_secs,
+            None => return None,
+        };
+        match self.secs.checked_add(extra_secs) {
+            Some(secs) => Some(Duration { secs, nanos: self.nanos }),
+            None => None,
+        }
+    }
+
+    /// Checked sum of an iterator of durations, returning [`None`] if overflow
+    /// occurred.
+    ///
+    /// Unlike `iter.sum::<Duration>()`, this does not panic on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_checked_sum_iter)]
+    /// use std::time::Duration;
+    ///
+    /// let durations = [Duration::from_secs(1), Duration::from_millis(500)];
+    /// let sum = Duration::checked_sum_iter(durations.into_iter());
+    /// assert_eq!(sum, Some(Duration::from_millis(1_500)));
+    ///
+    /// let durations = [Duration::MAX, Duration::from_secs(1)];
+    /// assert_eq!(Duration::checked_sum_iter(durations.into_iter()), None);
+    /// ```
+    #[unstable(feature = "duration_checked_sum_iter", issue = "none")]
+    pub fn checked_sum_iter<I: Iterator<Item = Duration>>(iter: I) -> Option<Duration> {
+        let mut total_secs: u64 = 0;
+        let mut total_nanos: u64 = 0;
+
+        for entry in iter {
+            total_secs = total_secs.checked_add(entry.secs)?;
+            total_nanos = match total_nanos.checked_add(entry.nanos.0 as u64) {
+                Some(n) => n,
+                None => {
+                    total_secs = total_secs.checked_add(total_nanos / NANOS_PER_SEC as u64)?;
+                    (total_nanos % NANOS_PER_SEC as u64) + entry.nanos.0 as u64
+                }
+            };
+        }
+        total_secs = total_secs.checked_add(total_nanos / NANOS_PER_SEC as u64)?;
+        total_nanos = total_nanos % NANOS_PER_SEC as u64;
+        Some(Duration::new(total_secs, total_nanos as u32))
+    }
+
+    /// Saturating `Duration` addition. Computes `self + other`, returning [`Duration::MAX`]
+    /// if overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_constants)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::new(0, 0).saturating_add(Duration::new(0, 1)), Duration::new(0, 1));
+    /// assert_eq!(Duration::new(1, 0).saturating_add(Duration::new(u64::MAX, 0)), Duration::MAX);
+    /// ```
+    #[stable(feature = "duration_saturating_ops", since = "1.53.0")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    #[rustc_const_stable(feature = "duration_consts_2", since = "1.58.0")]
+    pub const fn saturating_add(self, rhs: Duration) -> Duration {
+        match self.checked_add(rhs) {
+            Some(res) => res,
+            None => Duration::MAX,
+        }
+    }
+
+    /// Saturating addition of a number of whole seconds. Computes
+    /// `self + Duration::from_secs(secs)`, returning [`Duration::MAX`] if
+    /// overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_saturating_add_secs)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::new(1, 5).saturating_add_secs(2), Duration::new(3, 5));
+    /// assert_eq!(Duration::new(1, 0).saturating_add_secs(u64::MAX), Duration::MAX);
+    /// ```
+    #[unstable(feature = "duration_saturating_add_secs", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_saturating_add_secs", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn saturating_add_secs(self, secs: u64) -> Duration {
+        match self.secs.checked_add(secs) {
+            Some(secs) => Duration::new(secs, self.nanos.0),
+            None => Duration::MAX,
+        }
+    }
+
+    /// Wrapping addition of a number of nanoseconds. Computes `self + nanos`,
+    /// wrapping around at the boundary of the type.
+    ///
+    /// The sum is computed modulo `Duration::MAX + 1ns`, so that adding one
+    /// nanosecond to [`Duration::MAX`] gives [`Duration::ZERO`]. This matches the
+    /// behavior of a hardware timer that wraps around when it reaches its maximum
+    /// value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_wrapping)]
+    /// use std::time::Duration;
+    ///
+    /// let sum = Duration::new(1, 0).wrapping_add_nanos(1_500_000_000);
+    /// assert_eq!(sum, Duration::new(2, 500_000_000));
+    /// assert_eq!(Duration::MAX.wrapping_add_nanos(1), Duration::ZERO);
+    /// assert_eq!(Duration::MAX.wrapping_add_nanos(1_000_000_000), Duration::new(0, 999_999_999));
+    /// ```
+    #[unstable(feature = "duration_wrapping", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    #[rustc_const_unstable(feature = "duration_wrapping", issue = "none")]
+    pub const fn wrapping_add_nanos(self, nanos: u64) -> Duration {
+        let mut secs = self.secs.wrapping_add(nanos / NANOS_PER_SEC as u64);
+        let mut nanos = self.nanos.0 + (nanos % NANOS_PER_SEC as u64) as u32;
+        if nanos >= NANOS_PER_SEC {
+            nanos -= NANOS_PER_SEC;
+            // `Duration::MAX + 1ns` is a whole number of seconds, namely 2^64, so
+            // wrapping the seconds wraps the whole duration.
+            secs = secs.wrapping_add(1);
+        }
+        Duration::new(secs, nanos)
+    }
+
+    /// Wrapping `Duration` subtraction. Computes `self - rhs` modulo
+    /// `Duration::MAX + 1ns`.
+    #[inline]
+    const fn wrapping_sub(self, rhs: Duration) -> Duration {
+        let mut secs = self.secs.wrapping_sub(rhs.secs);
+        let nanos = if self.nanos.0 >= rhs.nanos.0 {
+            self.nanos.0 - rhs.nanos.0
+        } else {
+            secs = secs.wrapping_sub(1);
+            self.nanos.0 + NANOS_PER_SEC - rhs.nanos.0
+        };
+        Duration::new(secs, nanos)
+    }
+
+    /// Returns the number of nanoseconds elapsed from `start` to `self`, where
+    /// both are readings of a monotonic counter that wraps around.
+    ///
+    /// The difference is computed with wrapping arithmetic, so that if the counter
+    /// wrapped around past [`Duration::MAX`] between the two readings, and `self`
+    /// is therefore less than `start`, the result is still the elapsed time. The
+    /// result saturates at [`u64::MAX`] nanoseconds, about 584 years.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_wrapping)]
+    /// use std::time::Duration;
+    ///
+    /// let start = Duration::new(5, 0);
+    /// let now = Duration::new(6, 500);
+    /// assert_eq!(now.elapsed_nanos_since(start), 1_000_000_500);
+    ///
+    /// // The counter wrapped around between the two readings.
+    /// let start = Duration::MAX;
+    /// let now = Duration::new(0, 9);
+    /// assert_eq!(now.elapsed_nanos_since(start), 10);
+    /// ```
+    #[unstable(feature = "duration_wrapping", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    #[rustc_const_unstable(feature = "duration_wrapping", issue = "none")]
+    pub const fn elapsed_nanos_since(self, start: Duration) -> u64 {
+        let nanos = self.wrapping_sub(start).as_nanos();
+        if nanos > u64::MAX as u128 { u64::MAX } else { nanos as u64 }
+    }
+
+    /// Checked `Duration` subtraction. Computes `self - other`, returning [`None`]
+    /// if the result would be negative or if overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::new(0, 1).checked_sub(Duration::new(0, 0)), Some(Duration::new(0, 1)));
+    /// assert_eq!(Duration::new(0, 0).checked_sub(Duration::new(0, 1)), None);
+    /// ```
+    #[stable(feature = "duration_checked_ops", since = "1.16.0")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    #[rustc_const_stable(feature = "duration_consts_2", since = "1.58.0")]
+    pub const fn checked_sub(self, rhs: Duration) -> Option<Duration> {
+        if let Some(mut secs) = self.secs.checked_sub(rhs.secs) {
+            let nanos = if self.nanos.0 >= rhs.nanos.0 {
+                self.nanos.0 - rhs.nanos.0
+            } else if let Some(sub_secs) = secs.checked_sub(1) {
+                secs = sub_secs;
+                self.nanos.0 + NANOS_PER_SEC - rhs.nanos.0
+            } else {
+                return None;
+            };
+            debug_assert!(nanos < NANOS_PER_SEC);
+            Some(Duration::new(secs, nanos))
+        } else {
+            None
+        }
+    }
+
+    /// Fallible `Duration` subtraction. Computes `self - rhs`, returning an error
+    /// if the result would be negative.
+    ///
+    /// Unlike [`checked_sub`], the error tells by how much the result would
+    /// have been negative, which helps when debugging timer code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_try_sub)]
+    /// use std::time::Duration;
+    ///
+    /// let a = Duration::from_millis(10);
+    /// let b = Duration::from_millis(15);
+    /// assert_eq!(b.try_sub(a), Ok(Duration::from_millis(5)));
+    ///
+    /// let err = a.try_sub(b).unwrap_err();
+    /// assert_eq!(err.magnitude(), Duration::from_millis(5));
+    /// assert_eq!(err.to_string(), "duration underflowed by 5ms");
+    /// ```
+    ///
+    /// [`checked_sub`]: Duration::checked_sub
+    #[unstable(feature = "duration_try_sub", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_try_sub", issue = "none")]
+    #[inline]
+    pub const fn try_sub(self, rhs: Duration) -> Result<Duration, DurationUnderflowError> {
+        match self.checked_sub(rhs) {
+            Some(res) => Ok(res),
+            None => match rhs.checked_sub(self) {
+                Some(magnitude) => Err(DurationUnderflowError { magnitude }),
+                None => unreachable!(),
+            },
+        }
+    }
+
+    /// Saturating `Duration` subtraction. Computes `self - other`, returning [`Duration::ZERO`]
+    /// if the result would be negative or if overflow occurred. In other words, this is the
+    /// difference between the two durations, or zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::new(0, 1).saturating_sub(Duration::new(0, 0)), Duration::new(0, 1));
+    /// assert_eq!(Duration::new(0, 0).saturating_sub(Duration::new(0, 1)), Duration::ZERO);
+    /// ```
+    #[stable(feature = "duration_saturating_ops", since = "1.53.0")]
+    #[doc(alias = "checked_sub_or_zero", alias = "sub_or_zero", alias = "difference_or_zero")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    #[rustc_const_stable(feature = "duration_consts_2", since = "1.58.0")]
+    pub const fn saturating_sub(self, rhs: Duration) -> Duration {
+        match self.checked_sub(rhs) {
+            Some(res) => res,
             None => Duration::ZERO,
         }
     }
 
-    /// Checked `Duration` multiplication. Computes `self * other`, returning
-    /// [`None`] if overflow occurred.
+    /// Computes the absolute difference between `self` and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_abs_diff)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::new(100, 0).abs_diff(Duration::new(80, 0)), Duration::new(20, 0));
+    /// let a = Duration::new(100, 400_000_000);
+    /// assert_eq!(a.abs_diff(Duration::new(110, 0)), Duration::new(9, 600_000_000));
+    /// ```
+    #[unstable(feature = "duration_abs_diff", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_abs_diff", issue = "none")]
+    #[doc(alias = "distance")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn abs_diff(self, other: Duration) -> Duration {
+        match self.checked_sub(other) {
+            Some(res) => res,
+            None => match other.checked_sub(self) {
+                Some(res) => res,
+                None => unreachable!(),
+            },
+        }
+    }
+
+    /// Subtraction that reports a negative result instead of failing. Computes
+    /// `self - rhs`, returning `Ok` with the difference if `self >= rhs`, or
+    /// `Err` with the magnitude of the negative difference, `rhs - self`,
+    /// otherwise.
+    ///
+    /// Either way the returned duration is the same as [`abs_diff`], and the
+    /// variant tells whether the difference is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_sub_or_negative)]
+    /// use std::time::Duration;
+    ///
+    /// let a = Duration::from_millis(300);
+    /// let b = Duration::from_millis(500);
+    /// assert_eq!(b.wrapping_sub_or_negative(a), Ok(Duration::from_millis(200)));
+    /// assert_eq!(a.wrapping_sub_or_negative(b), Err(Duration::from_millis(200)));
+    /// assert_eq!(a.wrapping_sub_or_negative(a), Ok(Duration::ZERO));
+    /// ```
+    ///
+    /// [`abs_diff`]: Duration::abs_diff
+    #[unstable(feature = "duration_sub_or_negative", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_sub_or_negative", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn wrapping_sub_or_negative(self, rhs: Duration) -> Result<Duration, Duration> {
+        match self.checked_sub(rhs) {
+            Some(res) => Ok(res),
+            None => match rhs.checked_sub(self) {
+                Some(res) => Err(res),
+                None => unreachable!(),
+            },
+        }
+    }
+
+    /// Splits this `Duration` at `time`, as when consuming up to `time` out of a
+    /// budget of `self`.
+    ///
+    /// If `self >= time`, returns `time` together with the remainder
+    /// `Some(self - time)`. Otherwise the budget runs out before reaching `time`,
+    /// and this returns all of `self` together with [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_split_at)]
+    /// use std::time::Duration;
+    ///
+    /// let budget = Duration::from_millis(500);
+    /// let ms = Duration::from_millis;
+    /// assert_eq!(budget.split_at(ms(200)), (ms(200), Some(ms(300))));
+    /// assert_eq!(budget.split_at(ms(500)), (ms(500), Some(Duration::ZERO)));
+    /// assert_eq!(budget.split_at(ms(800)), (ms(500), None));
+    /// ```
+    #[unstable(feature = "duration_split_at", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_split_at", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn split_at(self, time: Duration) -> (Duration, Option<Duration>) {
+        match self.checked_sub(time) {
+            Some(rest) => (time, Some(rest)),
+            None => (self, None),
+        }
+    }
+
+    /// Checked `Duration` multiplication. Computes `self * other`, returning
+    /// [`None`] if overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::new(0, 500_000_001).checked_mul(2), Some(Duration::new(1, 2)));
+    /// assert_eq!(Duration::new(u64::MAX - 1, 0).checked_mul(2), None);
+    /// ```
+    #[stable(feature = "duration_checked_ops", since = "1.16.0")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    #[rustc_const_stable(feature = "duration_consts_2", since = "1.58.0")]
+    pub const fn checked_mul(self, rhs: u32) -> Option<Duration> {
+        // Multiply nanoseconds as u64, because it cannot overflow that way.
+        let total_nanos = self.nanos.0 as u64 * rhs as u64;
+        let extra_secs = total_nanos / (NANOS_PER_SEC as u64);
+        let nanos = (total_nanos % (NANOS_PER_SEC as u64)) as u32;
+        if let Some(s) = self.secs.checked_mul(rhs as u64) {
+            if let Some(secs) = s.checked_add(extra_secs) {
+                debug_assert!(nanos < NANOS_PER_SEC);
+                return Some(Duration::new(secs, nanos));
+            }
+        }
+        None
+    }
+
+    /// Checked fused multiply-add. Computes `self * rhs + addend`, returning
+    /// [`None`] if overflow occurred in either operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_checked_mul_add)]
+    /// use std::time::Duration;
+    ///
+    /// let period = Duration::from_millis(250);
+    /// let offset = Duration::from_millis(10);
+    /// assert_eq!(period.checked_mul_add(4, offset), Some(Duration::from_millis(1_010)));
+    /// assert_eq!(Duration::MAX.checked_mul_add(2, Duration::ZERO), None);
+    /// assert_eq!(Duration::MAX.checked_mul_add(1, Duration::NANOSECOND), None);
+    /// ```
+    #[unstable(feature = "duration_checked_mul_add", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_checked_mul_add", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_mul_add(self, rhs: u32, addend: Duration) -> Option<Duration> {
+        match self.checked_mul(rhs) {
+            Some(product) => product.checked_add(addend),
+            None => None,
+        }
+    }
+
+    /// Checked `Duration` multiplication by a [`u64`]. Computes `self * rhs`,
+    /// returning [`None`] if overflow occurred.
+    ///
+    /// This is useful to scale a per-second quantity by a number of seconds, or
+    /// more generally by any count that does not fit in a [`u32`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_mul_u64)]
+    /// use std::time::Duration;
+    ///
+    /// let per_second = Duration::from_millis(2);
+    /// let elapsed_secs = 1 << 40;
+    /// assert_eq!(per_second.checked_mul_u64(elapsed_secs), Some(Duration::from_millis(1 << 41)));
+    /// assert_eq!(Duration::new(u64::MAX - 1, 0).checked_mul_u64(2), None);
+    /// ```
+    #[unstable(feature = "duration_mul_u64", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_mul_u64", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    #[doc(alias = "mul_secs", alias = "accumulate")]
+    pub const fn checked_mul_u64(self, rhs: u64) -> Option<Duration> {
+        // Multiply as u128, because neither product can overflow that way.
+        let total_nanos = self.nanos.0 as u128 * rhs as u128;
+        let nanos = (total_nanos % NANOS_PER_SEC as u128) as u32;
+        let secs = self.secs as u128 * rhs as u128 + total_nanos / NANOS_PER_SEC as u128;
+        if secs <= u64::MAX as u128 { Some(Duration::new(secs as u64, nanos)) } else { None }
+    }
+
+    /// Checked doubling. Computes `self * 2`, returning [`None`] if overflow
+    /// occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_double_half)]
+    /// use std::time::Duration;
+    ///
+    /// let max_period = Duration::from_secs(60);
+    /// let mut retry_period = Duration::from_secs(1);
+    /// retry_period = retry_period.checked_double().unwrap_or(max_period);
+    /// assert_eq!(retry_period, Duration::from_secs(2));
+    /// assert_eq!(Duration::MAX.checked_double(), None);
+    /// ```
+    #[unstable(feature = "duration_double_half", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_double_half", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_double(self) -> Option<Duration> {
+        self.checked_mul(2)
+    }
+
+    /// Returns twice this `Duration`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows `Duration`. Use
+    /// [`checked_double`](Duration::checked_double) to handle overflow instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_double_half)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::from_millis(750).double(), Duration::from_millis(1_500));
+    /// ```
+    #[unstable(feature = "duration_double_half", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_double_half", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    #[track_caller]
+    pub const fn double(self) -> Duration {
+        match self.checked_double() {
+            Some(duration) => duration,
+            None => panic!("overflow when doubling duration"),
+        }
+    }
+
+    /// Returns half of this `Duration`, rounded down to whole nanoseconds.
+    ///
+    /// This is the same as `self / 2`, but never panics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_double_half)]
+    /// use std::time::Duration;
+    ///
+    /// let timeout = Duration::from_secs(5);
+    /// assert_eq!(timeout.half(), Duration::from_millis(2_500));
+    /// assert_eq!(Duration::from_nanos(3).half(), Duration::from_nanos(1));
+    /// ```
+    #[unstable(feature = "duration_double_half", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_double_half", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn half(self) -> Duration {
+        let carry = (self.secs % 2) as u32 * (NANOS_PER_SEC / 2);
+        Duration::new(self.secs / 2, carry + self.nanos.0 / 2)
+    }
+
+    /// Returns a quarter of this `Duration`, rounded down to whole nanoseconds.
+    ///
+    /// This is the same as `self / 4`, but never panics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_double_half)]
+    /// use std::time::Duration;
+    ///
+    /// let timeout = Duration::from_secs(5);
+    /// assert_eq!(timeout.quarter(), Duration::from_millis(1_250));
+    /// assert_eq!(Duration::from_nanos(7).quarter(), Duration::from_nanos(1));
+    /// ```
+    #[unstable(feature = "duration_double_half", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_double_half", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn quarter(self) -> Duration {
+        let carry = (self.secs % 4) as u32 * (NANOS_PER_SEC / 4);
+        Duration::new(self.secs / 4, carry + self.nanos.0 / 4)
+    }
+
+    /// Saturating `Duration` multiplication. Computes `self * other`, returning
+    /// [`Duration::MAX`] if overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_constants)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::new(0, 500_000_001).saturating_mul(2), Duration::new(1, 2));
+    /// assert_eq!(Duration::new(u64::MAX - 1, 0).saturating_mul(2), Duration::MAX);
+    /// ```
+    #[stable(feature = "duration_saturating_ops", since = "1.53.0")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    #[rustc_const_stable(feature = "duration_consts_2", since = "1.58.0")]
+    pub const fn saturating_mul(self, rhs: u32) -> Duration {
+        match self.checked_mul(rhs) {
+            Some(res) => res,
+            None => Duration::MAX,
+        }
+    }
+
+    /// Checked `Duration` division. Computes `self / other`, returning [`None`]
+    /// if `other == 0`.
+    ///
+    /// The result is the exact quotient rounded down to whole nanoseconds.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::new(2, 0).checked_div(2), Some(Duration::new(1, 0)));
+    /// assert_eq!(Duration::new(1, 0).checked_div(2), Some(Duration::new(0, 500_000_000)));
+    /// assert_eq!(Duration::new(1, 2).checked_div(3), Some(Duration::new(0, 333_333_334)));
+    /// assert_eq!(Duration::new(2, 0).checked_div(0), None);
+    /// ```
+    #[stable(feature = "duration_checked_ops", since = "1.16.0")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    #[rustc_const_stable(feature = "duration_consts_2", since = "1.58.0")]
+    pub const fn checked_div(self, rhs: u32) -> Option<Duration> {
+        if rhs != 0 {
+            let secs = self.secs / (rhs as u64);
+            let carry = self.secs - secs * (rhs as u64);
+            // `carry < rhs`, so this neither overflows nor exceeds a second.
+            let nanos = (carry * (NANOS_PER_SEC as u64) + self.nanos.0 as u64) / (rhs as u64);
+            let nanos = nanos as u32;
+            debug_assert!(nanos < NANOS_PER_SEC);
+            Some(Duration::new(secs, nanos))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of seconds contained by this `Duration` as `f64`.
+    ///
+    /// The returned value does include the fractional (nanosecond) part of the duration.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let dur = Duration::new(2, 700_000_000);
+    /// assert_eq!(dur.as_secs_f64(), 2.7);
+    /// ```
+    #[stable(feature = "duration_float", since = "1.38.0")]
+    #[must_use]
+    #[inline]
+    #[rustc_const_unstable(feature = "duration_consts_float", issue = "72440")]
+    pub const fn as_secs_f64(&self) -> f64 {
+        (self.secs as f64) + (self.nanos.0 as f64) / (NANOS_PER_SEC as f64)
+    }
+
+    /// Returns the number of seconds contained by this `Duration` as `f32`.
+    ///
+    /// The returned value does include the fractional (nanosecond) part of the duration.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let dur = Duration::new(2, 700_000_000);
+    /// assert_eq!(dur.as_secs_f32(), 2.7);
+    /// ```
+    #[stable(feature = "duration_float", since = "1.38.0")]
+    #[must_use]
+    #[inline]
+    #[rustc_const_unstable(feature = "duration_consts_float", issue = "72440")]
+    pub const fn as_secs_f32(&self) -> f32 {
+        (self.secs as f32) + (self.nanos.0 as f32) / (NANOS_PER_SEC as f32)
+    }
+
+    /// Returns the number of milliseconds contained by this `Duration` as `f64`.
+    ///
+    /// The returned value includes the fractional (nanosecond) part of the
+    /// duration. Unlike `as_millis() as f64`, the whole seconds and the
+    /// nanoseconds are converted separately, without an intermediate `u128`.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_float_units)]
+    /// use std::time::Duration;
+    ///
+    /// let dur = Duration::new(2, 345_678_000);
+    /// assert_eq!(dur.into_total_millis_f64(), 2_345.678);
+    /// ```
+    #[unstable(feature = "duration_float_units", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_float_units", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn into_total_millis_f64(&self) -> f64 {
+        (self.secs as f64) * (MILLIS_PER_SEC as f64)
+            + (self.nanos.0 as f64) / (NANOS_PER_MILLI as f64)
+    }
+
+    /// Returns the number of microseconds contained by this `Duration` as `f64`.
+    ///
+    /// The returned value includes the fractional (nanosecond) part of the
+    /// duration. Unlike `as_micros() as f64`, the whole seconds and the
+    /// nanoseconds are converted separately, without an intermediate `u128`.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_float_units)]
+    /// use std::time::Duration;
+    ///
+    /// let dur = Duration::new(2, 345_678_500);
+    /// assert_eq!(dur.into_total_micros_f64(), 2_345_678.5);
+    /// ```
+    #[unstable(feature = "duration_float_units", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_float_units", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn into_total_micros_f64(&self) -> f64 {
+        (self.secs as f64) * (MICROS_PER_SEC as f64)
+            + (self.nanos.0 as f64) / (NANOS_PER_MICRO as f64)
+    }
+
+    /// Returns the number of milliseconds contained by this `Duration` as `f64`.
+    ///
+    /// This is the same value as [`into_total_millis_f64`], under a name that
+    /// matches [`as_millis`] and [`as_secs_f64`].
+    ///
+    /// An `f64` represents every whole number of milliseconds exactly only up to
+    /// 2<sup>53</sup> milliseconds, about 285 thousand years. Longer durations
+    /// lose millisecond precision.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_float_units)]
+    /// use std::time::Duration;
+    ///
+    /// let dur = Duration::new(2, 345_678_000);
+    /// assert_eq!(dur.as_millis_f64(), 2_345.678);
+    /// ```
+    ///
+    /// [`into_total_millis_f64`]: Duration::into_total_millis_f64
+    /// [`as_millis`]: Duration::as_millis
+    /// [`as_secs_f64`]: Duration::as_secs_f64
+    #[unstable(feature = "duration_float_units", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_float_units", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn as_millis_f64(&self) -> f64 {
+        self.into_total_millis_f64()
+    }
+
+    /// Returns the number of microseconds contained by this `Duration` as `f64`.
+    ///
+    /// This is the same value as [`into_total_micros_f64`], under a name that
+    /// matches [`as_micros`] and [`as_secs_f64`].
+    ///
+    /// An `f64` represents every whole number of microseconds exactly only up to
+    /// 2<sup>53</sup> microseconds, about 285 years. Longer durations lose
+    /// microsecond precision.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_float_units)]
+    /// use std::time::Duration;
+    ///
+    /// let dur = Duration::new(2, 345_678_500);
+    /// assert_eq!(dur.as_micros_f64(), 2_345_678.5);
+    /// ```
+    ///
+    /// [`into_total_micros_f64`]: Duration::into_total_micros_f64
+    /// [`as_micros`]: Duration::as_micros
+    /// [`as_secs_f64`]: Duration::as_secs_f64
+    #[unstable(feature = "duration_float_units", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_float_units", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn as_micros_f64(&self) -> f64 {
+        self.into_total_micros_f64()
+    }
+
+    /// Returns the number of minutes contained by this `Duration` as `f64`.
+    ///
+    /// The returned value includes the fractional part of the duration, and is
+    /// the same as `self.as_secs_f64() / 60.0`.
+    ///
+    /// Most fractions of a minute cannot be represented exactly by an `f64`, so
+    /// the result is generally rounded, even for short durations: one second is
+    /// 0.016666666666666666 minutes. This is meant for display and reporting,
+    /// while exact computations should use the whole seconds and nanoseconds.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_float_units)]
+    /// use std::time::Duration;
+    ///
+    /// let dur = Duration::from_secs(90);
+    /// assert_eq!(dur.as_f64_mins(), 1.5);
+    /// ```
+    #[unstable(feature = "duration_float_units", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_float_units", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn as_f64_mins(&self) -> f64 {
+        self.as_secs_f64() / (SECS_PER_MINUTE as f64)
+    }
+
+    /// Returns the number of hours contained by this `Duration` as `f64`.
+    ///
+    /// The returned value includes the fractional part of the duration, and is
+    /// the same as `self.as_secs_f64() / 3600.0`. Like for
+    /// [`as_f64_mins`](Duration::as_f64_mins), the result is generally rounded.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_float_units)]
+    /// use std::time::Duration;
+    ///
+    /// let dur = Duration::from_secs(5_400);
+    /// assert_eq!(dur.as_f64_hours(), 1.5);
+    /// ```
+    #[unstable(feature = "duration_float_units", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_float_units", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn as_f64_hours(&self) -> f64 {
+        self.as_secs_f64() / ((SECS_PER_MINUTE * MINS_PER_HOUR) as f64)
+    }
+
+    /// Returns the number of days contained by this `Duration` as `f64`.
+    ///
+    /// The returned value includes the fractional part of the duration, and is
+    /// the same as `self.as_secs_f64() / 86400.0`. Like for
+    /// [`as_f64_mins`](Duration::as_f64_mins), the result is generally rounded.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_float_units)]
+    /// use std::time::Duration;
+    ///
+    /// let dur = Duration::from_secs(129_600);
+    /// assert_eq!(dur.as_f64_days(), 1.5);
+    /// ```
+    #[unstable(feature = "duration_float_units", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_float_units", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn as_f64_days(&self) -> f64 {
+        self.as_secs_f64() / ((SECS_PER_MINUTE * MINS_PER_HOUR * HOURS_PER_DAY) as f64)
+    }
+
+    /// Returns the fractional part of this `Duration`, in seconds, as `f64`.
+    ///
+    /// The returned value is in the range `[0.0, 1.0)`, which makes it suitable
+    /// for example as the phase of something that repeats every second.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_float_units)]
+    /// use std::time::Duration;
+    ///
+    /// let dur = Duration::new(2, 250_000_000);
+    /// assert_eq!(dur.subsec_nanos_as_f64(), 0.25);
+    /// ```
+    #[unstable(feature = "duration_float_units", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_float_units", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn subsec_nanos_as_f64(&self) -> f64 {
+        (self.nanos.0 as f64) / (NANOS_PER_SEC as f64)
+    }
+
+    /// Returns the fractional part of this `Duration`, in seconds, as `f32`.
+    ///
+    /// The returned value is in the range `[0.0, 1.0)`, which makes it suitable
+    /// for example as the phase of something that repeats every second.
+    /// Fractions too close to one to be distinguished from it in `f32` are
+    /// rounded down to the largest `f32` below one.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_float_units)]
+    /// use std::time::Duration;
+    ///
+    /// let dur = Duration::new(2, 250_000_000);
+    /// assert_eq!(dur.subsec_nanos_as_f32(), 0.25);
+    /// assert!(Duration::new(0, 999_999_999).subsec_nanos_as_f32() < 1.0);
+    /// ```
+    #[unstable(feature = "duration_float_units", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_float_units", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn subsec_nanos_as_f32(&self) -> f32 {
+        let fraction = (self.nanos.0 as f32) / (NANOS_PER_SEC as f32);
+        if fraction < 1.0 { fraction } else { 1.0 - f32::EPSILON / 2.0 }
+    }
+
+    /// Creates a new `Duration` from the specified number of seconds represented
+    /// as `f64`.
+    ///
+    /// # Panics
+    /// This constructor will panic if `secs` is negative, overflows `Duration` or not finite.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let res = Duration::from_secs_f64(0.0);
+    /// assert_eq!(res, Duration::new(0, 0));
+    /// let res = Duration::from_secs_f64(1e-20);
+    /// assert_eq!(res, Duration::new(0, 0));
+    /// let res = Duration::from_secs_f64(4.2e-7);
+    /// assert_eq!(res, Duration::new(0, 420));
+    /// let res = Duration::from_secs_f64(2.7);
+    /// assert_eq!(res, Duration::new(2, 700_000_000));
+    /// let res = Duration::from_secs_f64(3e10);
+    /// assert_eq!(res, Duration::new(30_000_000_000, 0));
+    /// // subnormal float
+    /// let res = Duration::from_secs_f64(f64::from_bits(1));
+    /// assert_eq!(res, Duration::new(0, 0));
+    /// // conversion uses rounding
+    /// let res = Duration::from_secs_f64(0.999e-9);
+    /// assert_eq!(res, Duration::new(0, 1));
+    /// ```
+    #[stable(feature = "duration_float", since = "1.38.0")]
+    #[must_use]
+    #[inline]
+    #[rustc_const_unstable(feature = "duration_consts_float", issue = "72440")]
+    pub const fn from_secs_f64(secs: f64) -> Duration {
+        match Duration::try_from_secs_f64(secs) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e.description()),
+        }
+    }
+
+    /// Creates a new `Duration` from the specified number of seconds represented
+    /// as `f32`.
+    ///
+    /// # Panics
+    /// This constructor will panic if `secs` is negative, overflows `Duration` or not finite.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let res = Duration::from_secs_f32(0.0);
+    /// assert_eq!(res, Duration::new(0, 0));
+    /// let res = Duration::from_secs_f32(1e-20);
+    /// assert_eq!(res, Duration::new(0, 0));
+    /// let res = Duration::from_secs_f32(4.2e-7);
+    /// assert_eq!(res, Duration::new(0, 420));
+    /// let res = Duration::from_secs_f32(2.7);
+    /// assert_eq!(res, Duration::new(2, 700_000_048));
+    /// let res = Duration::from_secs_f32(3e10);
+    /// assert_eq!(res, Duration::new(30_000_001_024, 0));
+    /// // subnormal float
+    /// let res = Duration::from_secs_f32(f32::from_bits(1));
+    /// assert_eq!(res, Duration::new(0, 0));
+    /// // conversion uses rounding
+    /// let res = Duration::from_secs_f32(0.999e-9);
+    /// assert_eq!(res, Duration::new(0, 1));
+    /// ```
+    #[stable(feature = "duration_float", since = "1.38.0")]
+    #[must_use]
+    #[inline]
+    #[rustc_const_unstable(feature = "duration_consts_float", issue = "72440")]
+    pub const fn from_secs_f32(secs: f32) -> Duration {
+        match Duration::try_from_secs_f32(secs) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e.description()),
+        }
+    }
+
+    /// Multiplies `Duration` by `f64`.
+    ///
+    /// # Panics
+    /// This method will panic if result is negative, overflows `Duration` or not finite.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let dur = Duration::new(2, 700_000_000);
+    /// assert_eq!(dur.mul_f64(3.14), Duration::new(8, 478_000_000));
+    /// assert_eq!(dur.mul_f64(3.14e5), Duration::new(847_800, 0));
+    /// ```
+    #[stable(feature = "duration_float", since = "1.38.0")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    #[rustc_const_unstable(feature = "duration_consts_float", issue = "72440")]
+    pub const fn mul_f64(self, rhs: f64) -> Duration {
+        Duration::from_secs_f64(rhs * self.as_secs_f64())
+    }
+
+    /// Multiplies `Duration` by `f32`.
+    ///
+    /// # Panics
+    /// This method will panic if result is negative, overflows `Duration` or not finite.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let dur = Duration::new(2, 700_000_000);
+    /// assert_eq!(dur.mul_f32(3.14), Duration::new(8, 478_000_641));
+    /// assert_eq!(dur.mul_f32(3.14e5), Duration::new(847800, 0));
+    /// ```
+    #[stable(feature = "duration_float", since = "1.38.0")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    #[rustc_const_unstable(feature = "duration_consts_float", issue = "72440")]
+    pub const fn mul_f32(self, rhs: f32) -> Duration {
+        Duration::from_secs_f32(rhs * self.as_secs_f32())
+    }
+
+    /// Multiplies `Duration` by `f64`, clamping the result instead of panicking.
+    ///
+    /// This is the same as [`mul_f64`](Duration::mul_f64), except that a
+    /// result that overflows `Duration`, including a positive infinity, gives
+    /// [`Duration::MAX`], a negative result gives [`Duration::ZERO`], and a
+    /// NaN `rhs` gives `self` unchanged. This makes it suitable for scale
+    /// factors that are not under the caller's control, such as in game and
+    /// simulation code.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_float_ops)]
+    /// use std::time::Duration;
+    ///
+    /// let dur = Duration::new(2, 700_000_000);
+    /// assert_eq!(dur.approx_mul_f64_safe(3.14), Duration::new(8, 478_000_000));
+    /// assert_eq!(dur.approx_mul_f64_safe(1e300), Duration::MAX);
+    /// assert_eq!(dur.approx_mul_f64_safe(-1.0), Duration::ZERO);
+    /// assert_eq!(dur.approx_mul_f64_safe(f64::NAN), dur);
+    /// ```
+    #[unstable(feature = "duration_float_ops", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_float_ops", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn approx_mul_f64_safe(self, rhs: f64) -> Duration {
+        // Only NaN is not equal to itself.
+        if rhs != rhs {
+            return self;
+        }
+        let secs = rhs * self.as_secs_f64();
+        match Duration::try_from_secs_f64(secs) {
+            Ok(duration) => duration,
+            Err(_) if secs > 0.0 => Duration::MAX,
+            // The result is negative, or NaN because `self` is zero and `rhs`
+            // is infinite.
+            Err(_) => Duration::ZERO,
+        }
+    }
+
+    /// Divide `Duration` by `f64`.
+    ///
+    /// # Panics
+    /// This method will panic if result is negative, overflows `Duration` or not finite.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let dur = Duration::new(2, 700_000_000);
+    /// assert_eq!(dur.div_f64(3.14), Duration::new(0, 859_872_611));
+    /// assert_eq!(dur.div_f64(3.14e5), Duration::new(0, 8_599));
+    /// ```
+    #[stable(feature = "duration_float", since = "1.38.0")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    #[rustc_const_unstable(feature = "duration_consts_float", issue = "72440")]
+    pub const fn div_f64(self, rhs: f64) -> Duration {
+        Duration::from_secs_f64(self.as_secs_f64() / rhs)
+    }
+
+    /// Divide `Duration` by `f32`.
+    ///
+    /// # Panics
+    /// This method will panic if result is negative, overflows `Duration` or not finite.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let dur = Duration::new(2, 700_000_000);
+    /// // note that due to rounding errors result is slightly
+    /// // different from 0.859_872_611
+    /// assert_eq!(dur.div_f32(3.14), Duration::new(0, 859_872_580));
+    /// assert_eq!(dur.div_f32(3.14e5), Duration::new(0, 8_599));
+    /// ```
+    #[stable(feature = "duration_float", since = "1.38.0")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    #[rustc_const_unstable(feature = "duration_consts_float", issue = "72440")]
+    pub const fn div_f32(self, rhs: f32) -> Duration {
+        Duration::from_secs_f32(self.as_secs_f32() / rhs)
+    }
+
+    /// Divide `Duration` by `f64`, returning [`None`] instead of panicking.
+    ///
+    /// Returns [`None`] if `rhs` is zero or NaN, or if the result would be
+    /// negative, overflow `Duration` or not be finite. This lets the caller
+    /// handle a zero divisor, for example an empty rate, with a default of its
+    /// choice.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_float_ops)]
+    /// use std::time::Duration;
+    ///
+    /// let dur = Duration::new(2, 700_000_000);
+    /// assert_eq!(dur.approx_div(3.14), Some(Duration::new(0, 859_872_611)));
+    /// assert_eq!(dur.approx_div(0.0), None);
+    /// assert_eq!(dur.approx_div(f64::NAN), None);
+    /// assert_eq!(dur.approx_div(-1.0), None);
+    /// assert_eq!(Duration::MAX.approx_div(0.5), None);
+    /// ```
+    #[unstable(feature = "duration_float_ops", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_float_ops", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    #[doc(alias = "checked_div_f64")]
+    pub const fn approx_div(self, rhs: f64) -> Option<Duration> {
+        if rhs == 0.0 {
+            // Dividing by zero gives an infinity or, if `self` is zero, NaN.
+            return None;
+        }
+        match Duration::try_from_secs_f64(self.as_secs_f64() / rhs) {
+            Ok(duration) => Some(duration),
+            Err(_) => None,
+        }
+    }
+
+    /// Extends `Duration` by `percent` percent, computing
+    /// `self * (1.0 + percent / 100.0)`.
+    ///
+    /// Returns [`None`] if `percent` is negative, infinite or NaN, or if the
+    /// result would overflow `Duration`.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_float_ops)]
+    /// use std::time::Duration;
+    ///
+    /// let timeout = Duration::from_secs(20);
+    /// assert_eq!(timeout.extend_by_percentage(10.0), Some(Duration::from_secs(22)));
+    /// assert_eq!(timeout.extend_by_percentage(0.0), Some(timeout));
+    /// assert_eq!(timeout.extend_by_percentage(-10.0), None);
+    /// assert_eq!(timeout.extend_by_percentage(f64::NAN), None);
+    /// assert_eq!(Duration::MAX.extend_by_percentage(100.0), None);
+    /// ```
+    #[unstable(feature = "duration_float_ops", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_float_ops", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn extend_by_percentage(self, percent: f64) -> Option<Duration> {
+        // This also rejects NaN, for which every comparison is false.
+        if !(percent >= 0.0 && percent < f64::INFINITY) {
+            return None;
+        }
+        match Duration::try_from_secs_f64(self.as_secs_f64() * (1.0 + percent / 100.0)) {
+            Ok(duration) => Some(duration),
+            Err(_) => None,
+        }
+    }
+
+    /// Shrinks `Duration` by `percent` percent, computing
+    /// `self * (1.0 - percent / 100.0)`.
+    ///
+    /// Returns [`None`] if `percent` is negative, greater than 100, or NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_float_ops)]
+    /// use std::time::Duration;
+    ///
+    /// let timeout = Duration::from_secs(20);
+    /// assert_eq!(timeout.shrink_by_percentage(25.0), Some(Duration::from_secs(15)));
+    /// assert_eq!(timeout.shrink_by_percentage(100.0), Some(Duration::ZERO));
+    /// assert_eq!(timeout.shrink_by_percentage(101.0), None);
+    /// assert_eq!(timeout.shrink_by_percentage(-10.0), None);
+    /// assert_eq!(timeout.shrink_by_percentage(f64::NAN), None);
+    /// ```
+    #[unstable(feature = "duration_float_ops", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_float_ops", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn shrink_by_percentage(self, percent: f64) -> Option<Duration> {
+        // This also rejects NaN, for which every comparison is false.
+        if !(percent >= 0.0 && percent <= 100.0) {
+            return None;
+        }
+        match Duration::try_from_secs_f64(self.as_secs_f64() * (1.0 - percent / 100.0)) {
+            Ok(duration) => Some(duration),
+            Err(_) => None,
+        }
+    }
+
+    /// Divide `Duration` by `Duration` and return `f64`.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(div_duration)]
+    /// use std::time::Duration;
+    ///
+    /// let dur1 = Duration::new(2, 700_000_000);
+    /// let dur2 = Duration::new(5, 400_000_000);
+    /// assert_eq!(dur1.div_duration_f64(dur2), 0.5);
+    /// ```
+    #[unstable(feature = "div_duration", issue = "63139")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    #[rustc_const_unstable(feature = "duration_consts_float", issue = "72440")]
+    pub const fn div_duration_f64(self, rhs: Duration) -> f64 {
+        self.as_secs_f64() / rhs.as_secs_f64()
+    }
+
+    /// Divide `Duration` by `Duration` and return `f32`.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(div_duration)]
+    /// use std::time::Duration;
+    ///
+    /// let dur1 = Duration::new(2, 700_000_000);
+    /// let dur2 = Duration::new(5, 400_000_000);
+    /// assert_eq!(dur1.div_duration_f32(dur2), 0.5);
+    /// ```
+    #[unstable(feature = "div_duration", issue = "63139")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    #[rustc_const_unstable(feature = "duration_consts_float", issue = "72440")]
+    pub const fn div_duration_f32(self, rhs: Duration) -> f32 {
+        self.as_secs_f32() / rhs.as_secs_f32()
+    }
+
+    /// Returns the reciprocal of this `Duration` in inverse nanoseconds, that
+    /// is, how many times it fits into one nanosecond.
+    ///
+    /// For a latency, this is the corresponding rate in events per nanosecond;
+    /// multiply by `1e9` for events per second. A zero duration gives
+    /// [`f64::INFINITY`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_reciprocal)]
+    /// use std::time::Duration;
+    ///
+    /// let latency = Duration::from_micros(4);
+    /// assert_eq!(latency.reciprocal_nanos(), 0.00025);
+    /// assert_eq!(latency.reciprocal_nanos() * 1e9, 250_000.0);
+    /// assert_eq!(Duration::ZERO.reciprocal_nanos(), f64::INFINITY);
+    /// ```
+    #[unstable(feature = "duration_reciprocal", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_reciprocal", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn reciprocal_nanos(self) -> f64 {
+        1.0 / self.as_nanos() as f64
+    }
+
+    /// Returns the population variance of `durations`, in square nanoseconds.
+    ///
+    /// The mean is computed in a first pass over the samples and the squared
+    /// deviations from it are summed in a second one, which keeps the rounding
+    /// error small for samples that are clustered far away from zero. The standard
+    /// deviation in nanoseconds is the square root of the returned value.
+    ///
+    /// Returns [`None`] if `durations` contains fewer than two samples.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_stats)]
+    /// use std::time::Duration;
+    ///
+    /// let samples = [2, 4, 4, 4, 5, 5, 7, 9].map(Duration::from_nanos);
+    /// assert_eq!(Duration::variance_nanos(&samples), Some(4.0));
+    ///
+    /// assert_eq!(Duration::variance_nanos(&[Duration::SECOND]), None);
+    /// assert_eq!(Duration::variance_nanos(&[]), None);
+    /// ```
+    #[unstable(feature = "duration_stats", issue = "none")]
+    #[must_use]
+    pub fn variance_nanos(durations: &[Duration]) -> Option<f64> {
+        if durations.len() < 2 {
+            return None;
+        }
+        let n = durations.len() as f64;
+        let mean = durations.iter().map(|d| d.as_nanos() as f64).sum::<f64>() / n;
+        let squares = durations
+            .iter()
+            .map(|d| {
+                let deviation = d.as_nanos() as f64 - mean;
+                deviation * deviation
+            })
+            .sum::<f64>();
+        Some(squares / n)
+    }
+
+    /// Returns the weighted mean of `samples`, each of which is a duration and
+    /// its weight.
+    ///
+    /// This computes `sum(d * w) / sum(w)` using integer arithmetic on whole
+    /// nanoseconds, and the result is rounded down to whole nanoseconds. A
+    /// sample with a weight of zero is ignored.
+    ///
+    /// Returns [`None`] if the total weight is zero, which includes the case of
+    /// no samples, or if the weighted sum overflows a `u128`.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_stats)]
+    /// use std::time::Duration;
+    ///
+    /// let samples = [(Duration::from_millis(10), 3), (Duration::from_millis(30), 1)];
+    /// assert_eq!(Duration::weighted_average(&samples), Some(Duration::from_millis(15)));
+    ///
+    /// assert_eq!(Duration::weighted_average(&[(Duration::SECOND, 0)]), None);
+    /// assert_eq!(Duration::weighted_average(&[]), None);
+    /// ```
+    #[unstable(feature = "duration_stats", issue = "none")]
+    #[must_use]
+    pub fn weighted_average(samples: &[(Duration, u32)]) -> Option<Duration> {
+        let mut weighted_sum: u128 = 0;
+        let mut total_weight: u128 = 0;
+        for &(duration, weight) in samples {
+            // `Duration::MAX.as_nanos() * u32::MAX as u128` does not overflow, only the sum can.
+            weighted_sum = weighted_sum.checked_add(duration.as_nanos() * weight as u128)?;
+            total_weight += weight as u128;
+        }
+        if total_weight == 0 {
+            return None;
+        }
+        // The weighted mean is at most the longest sample, so it fits in a `Duration`.
+        Duration::from_nanos_u128(weighted_sum / total_weight)
+    }
+
+    /// Returns the interquartile range of `sorted`, the difference between its
+    /// third and first quartiles.
+    ///
+    /// The samples in `sorted` must be sorted in ascending order, which the
+    /// caller is responsible for. The first quartile is the median of the lower
+    /// half of the samples and the third quartile the median of the upper half,
+    /// leaving out the middle sample if there is an odd number of them. The
+    /// median of an even number of samples is the midpoint of the two middle
+    /// ones, rounded down to whole nanoseconds.
+    ///
+    /// Returns [`None`] if `sorted` contains fewer than four samples.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_stats)]
+    /// use std::time::Duration;
+    ///
+    /// let mut samples = [7, 1, 3, 9, 5, 2, 8, 4].map(Duration::from_millis);
+    /// samples.sort();
+    /// assert_eq!(Duration::interquartile_range(&samples), Some(Duration::from_millis(5)));
+    ///
+    /// assert_eq!(Duration::interquartile_range(&samples[..3]), None);
+    /// ```
+    #[unstable(feature = "duration_stats", issue = "none")]
+    #[must_use]
+    pub fn interquartile_range(sorted: &[Duration]) -> Option<Duration> {
+        debug_assert!(sorted.windows(2).all(|w| w[0] <= w[1]), "samples are not sorted");
+        let n = sorted.len();
+        if n < 4 {
+            return None;
+        }
+        let first_quartile = sorted_median(&sorted[..n / 2]);
+        let third_quartile = sorted_median(&sorted[(n + 1) / 2..]);
+        Some(third_quartile - first_quartile)
+    }
+
+    /// Returns the median of `sorted`.
+    ///
+    /// The samples in `sorted` must be sorted in ascending order, which the
+    /// caller is responsible for. For an odd number of samples, this is the
+    /// middle one, and for an even number of samples the midpoint of the two
+    /// middle ones, rounded down to whole nanoseconds.
+    ///
+    /// Returns [`None`] if `sorted` is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_stats)]
+    /// use std::time::Duration;
+    ///
+    /// let mut samples = [40, 10, 30, 20].map(Duration::from_millis);
+    /// samples.sort();
+    /// assert_eq!(Duration::median(&samples), Some(Duration::from_millis(25)));
+    /// assert_eq!(Duration::median(&samples[..3]), Some(Duration::from_millis(20)));
+    ///
+    /// assert_eq!(Duration::median(&[]), None);
+    /// ```
+    #[unstable(feature = "duration_stats", issue = "none")]
+    #[must_use]
+    pub fn median(sorted: &[Duration]) -> Option<Duration> {
+        debug_assert!(sorted.windows(2).all(|w| w[0] <= w[1]), "samples are not sorted");
+        if sorted.is_empty() { None } else { Some(sorted_median(sorted)) }
+    }
+
+    /// Returns the `p`th percentile of `sorted`, for `p` from 0 to 100.
+    ///
+    /// The samples in `sorted` must be sorted in ascending order, which the
+    /// caller is responsible for. The percentile is interpolated linearly
+    /// between the two samples nearest to rank `p / 100 * (sorted.len() - 1)`
+    /// and rounded down to whole nanoseconds, so that the 0th percentile is the
+    /// shortest sample, the 100th percentile the longest one, and the 50th
+    /// percentile is the [median](Duration::median).
+    ///
+    /// Returns [`None`] if `sorted` is empty or if `p` is not in the range
+    /// `0.0..=100.0`, which includes the case of NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_stats)]
+    /// use std::time::Duration;
+    ///
+    /// let samples: Vec<_> = (1..=100).map(Duration::from_millis).collect();
+    /// assert_eq!(Duration::percentile(&samples, 99.0), Some(Duration::from_micros(99_010)));
+    /// assert_eq!(Duration::percentile(&samples, 50.0), Duration::median(&samples));
+    /// assert_eq!(Duration::percentile(&samples, 100.0), Some(Duration::from_millis(100)));
+    ///
+    /// assert_eq!(Duration::percentile(&samples, 101.0), None);
+    /// assert_eq!(Duration::percentile(&[], 50.0), None);
+    /// ```
+    #[unstable(feature = "duration_stats", issue = "none")]
+    #[must_use]
+    pub fn percentile(sorted: &[Duration], p: f64) -> Option<Duration> {
+        debug_assert!(sorted.windows(2).all(|w| w[0] <= w[1]), "samples are not sorted");
+        // This also rejects NaN, for which every comparison is false.
+        if sorted.is_empty() || !(p >= 0.0 && p <= 100.0) {
+            return None;
+        }
+        let rank = p / 100.0 * (sorted.len() - 1) as f64;
+        // `rank` is non-negative and at most `sorted.len() - 1`.
+        let index = rank as usize;
+        let (low, high) = match sorted.get(index + 1) {
+            Some(&high) => (sorted[index], high),
+            None => return Some(sorted[index]),
+        };
+        let distance = (high - low).as_nanos();
+        // Rounding `distance` to an `f64` could make this larger than `distance`.
+        let offset = ((distance as f64 * (rank - index as f64)) as u128).min(distance);
+        Some(low + Duration::from_nanos_u128(offset)?)
+    }
+
+    /// Returns the index of the bucket that contains `self` when time is divided
+    /// into consecutive buckets of length `bucket_size`, starting at zero.
+    ///
+    /// Bucket `i` covers the durations from `bucket_size * i` up to, but not
+    /// including, `bucket_size * (i + 1)`. The index is a `u128` so that even one
+    /// nanosecond buckets can index all of `Duration`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bucket_size` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_stats)]
+    /// use std::time::Duration;
+    ///
+    /// let bucket = Duration::from_millis(10);
+    /// assert_eq!(Duration::from_millis(9).bucketize(bucket), 0);
+    /// assert_eq!(Duration::from_millis(10).bucketize(bucket), 1);
+    /// assert_eq!(Duration::from_millis(57).bucketize(bucket), 5);
+    /// ```
+    #[unstable(feature = "duration_stats", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_stats", issue = "none")]
+    #[must_use]
+    #[inline]
+    #[track_caller]
+    pub const fn bucketize(self, bucket_size: Duration) -> u128 {
+        if bucket_size.is_zero() {
+            panic!("divide by zero error when computing the bucket of a duration");
+        }
+        self.as_nanos() / bucket_size.as_nanos()
+    }
+
+    /// Returns the `seconds` and `nanos` fields of the Protocol Buffers
+    /// `google.protobuf.Duration` message that represents this `Duration`, or
+    /// [`None`] if the whole seconds do not fit in an [`i64`].
+    ///
+    /// Since a `Duration` is never negative, `nanos` is always in the range
+    /// `0..=999_999_999` and has the same sign as `seconds`, as the message
+    /// requires.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_protobuf)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::new(5, 300).to_protobuf_checked(), Some((5, 300)));
+    /// assert_eq!(Duration::new(i64::MAX as u64, 0).to_protobuf_checked(), Some((i64::MAX, 0)));
+    /// assert_eq!(Duration::MAX.to_protobuf_checked(), None);
+    /// ```
+    #[unstable(feature = "duration_protobuf", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_protobuf", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn to_protobuf_checked(self) -> Option<(i64, i32)> {
+        if self.secs > i64::MAX as u64 {
+            None
+        } else {
+            // `self.nanos` is less than 10^9, which is less than `i32::MAX`.
+            Some((self.secs as i64, self.nanos.0 as i32))
+        }
+    }
+
+    /// Returns the 12-byte little-endian representation of this `Duration`,
+    /// without any padding.
+    ///
+    /// The whole seconds are stored in the first 8 bytes and the nanoseconds in
+    /// the last 4 bytes. Use [`from_u96_le`] to convert the bytes back.
+    ///
+    /// [`from_u96_le`]: Duration::from_u96_le
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_bytes)]
+    /// use std::time::Duration;
+    ///
+    /// let bytes = Duration::new(0x0102, 0x0304).to_u96_le();
+    /// assert_eq!(bytes, [0x02, 0x01, 0, 0, 0, 0, 0, 0, 0x04, 0x03, 0, 0]);
+    /// ```
+    #[unstable(feature = "duration_bytes", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_bytes", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn to_u96_le(self) -> [u8; 12] {
+        let secs = self.secs.to_le_bytes();
+        let nanos = self.nanos.0.to_le_bytes();
+        let mut bytes = [0; 12];
+        let mut i = 0;
+        while i < 8 {
+            bytes[i] = secs[i];
+            i += 1;
+        }
+        while i < 12 {
+            bytes[i] = nanos[i - 8];
+            i += 1;
+        }
+        bytes
+    }
+
+    /// Creates a `Duration` from its 12-byte little-endian representation, as
+    /// returned by [`to_u96_le`].
+    ///
+    /// Returns [`None`] if the nanoseconds stored in the last 4 bytes are not
+    /// less than 1,000,000,000.
+    ///
+    /// [`to_u96_le`]: Duration::to_u96_le
     ///
     /// # Examples
+    /// ```
+    /// #![feature(duration_bytes)]
+    /// use std::time::Duration;
     ///
-    /// Basic usage:
+    /// let bytes = [0x02, 0x01, 0, 0, 0, 0, 0, 0, 0x04, 0x03, 0, 0];
+    /// assert_eq!(Duration::from_u96_le(bytes), Some(Duration::new(0x0102, 0x0304)));
+    ///
+    /// let too_many_nanos = [0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff, 0xff, 0xff];
+    /// assert_eq!(Duration::from_u96_le(too_many_nanos), None);
+    /// ```
+    #[unstable(feature = "duration_bytes", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_bytes", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn from_u96_le(bytes: [u8; 12]) -> Option<Duration> {
+        let mut secs = [0; 8];
+        let mut nanos = [0; 4];
+        let mut i = 0;
+        while i < 8 {
+            secs[i] = bytes[i];
+            i += 1;
+        }
+        while i < 12 {
+            nanos[i - 8] = bytes[i];
+            i += 1;
+        }
+        let nanos = u32::from_le_bytes(nanos);
+        if nanos < NANOS_PER_SEC {
+            Some(Duration::new(u64::from_le_bytes(secs), nanos))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the 12-byte big-endian representation of this `Duration`,
+    /// without any padding.
+    ///
+    /// The whole seconds are stored in the first 8 bytes and the nanoseconds in
+    /// the last 4 bytes. Use [`from_be_bytes`] to convert the bytes back.
     ///
+    /// [`from_be_bytes`]: Duration::from_be_bytes
+    ///
+    /// # Examples
     /// ```
+    /// #![feature(duration_bytes)]
     /// use std::time::Duration;
     ///
-    /// assert_eq!(Duration::new(0, 500_000_001).checked_mul(2), Some(Duration::new(1, 2)));
-    /// assert_eq!(Duration::new(u64::MAX - 1, 0).checked_mul(2), None);
+    /// let bytes = Duration::new(0x0102, 0x0304).to_be_bytes();
+    /// assert_eq!(bytes, [0, 0, 0, 0, 0, 0, 0x01, 0x02, 0, 0, 0x03, 0x04]);
     /// ```
-    #[stable(feature = "duration_checked_ops", since = "1.16.0")]
+    #[unstable(feature = "duration_bytes", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_bytes", issue = "none")]
     #[must_use = "this returns the result of the operation, \
                   without modifying the original"]
     #[inline]
-    #[rustc_const_stable(feature = "duration_consts_2", since = "1.58.0")]
-    pub const fn checked_mul(self, rhs: u32) -> Option<Duration> {
-        // Multiply nanoseconds as u64, because it cannot overflow that way.
-        let total_nanos = self.nanos.0 as u64 * rhs as u64;
-        let extra_secs = total_nanos / (NANOS_PER_SEC as u64);
-        let nanos = (total_nanos % (NANOS_PER_SEC as u64)) as u32;
-        if let Some(s) = self.secs.checked_mul(rhs as u64) {
-            if let Some(secs) = s.checked_add(extra_secs) {
-                debug_assert!(nanos < NANOS_PER_SEC);
-                return Some(Duration::new(secs, nanos));
-            }
+    pub const fn to_be_bytes(self) -> [u8; 12] {
+        let secs = self.secs.to_be_bytes();
+        let nanos = self.nanos.0.to_be_bytes();
+        let mut bytes = [0; 12];
+        let mut i = 0;
+        while i < 8 {
+            bytes[i] = secs[i];
+            i += 1;
         }
-        None
+        while i < 12 {
+            bytes[i] = nanos[i - 8];
+            i += 1;
+        }
+        bytes
     }
 
-    /// Saturating `Duration` multiplication. Computes `self * other`, returning
-    /// [`Duration::MAX`] if overflow occurred.
+    /// Creates a `Duration` from its 12-byte big-endian representation,
+    /// returning [`None`] if the nanoseconds are not less than one second.
+    ///
+    /// The whole seconds are read from the first 8 bytes and the nanoseconds from
+    /// the last 4 bytes, as written by [`to_be_bytes`].
+    ///
+    /// [`to_be_bytes`]: Duration::to_be_bytes
     ///
     /// # Examples
+    /// ```
+    /// #![feature(duration_bytes)]
+    /// use std::time::Duration;
     ///
+    /// let bytes = [0, 0, 0, 0, 0, 0, 0x01, 0x02, 0, 0, 0x03, 0x04];
+    /// assert_eq!(Duration::from_be_bytes(bytes), Some(Duration::new(0x0102, 0x0304)));
+    ///
+    /// let too_many_nanos = [0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff, 0xff, 0xff];
+    /// assert_eq!(Duration::from_be_bytes(too_many_nanos), None);
     /// ```
-    /// #![feature(duration_constants)]
+    #[unstable(feature = "duration_bytes", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_bytes", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn from_be_bytes(bytes: [u8; 12]) -> Option<Duration> {
+        let mut secs = [0; 8];
+        let mut nanos = [0; 4];
+        let mut i = 0;
+        while i < 8 {
+            secs[i] = bytes[i];
+            i += 1;
+        }
+        while i < 12 {
+            nanos[i - 8] = bytes[i];
+            i += 1;
+        }
+        let nanos = u32::from_be_bytes(nanos);
+        if nanos < NANOS_PER_SEC {
+            Some(Duration::new(u64::from_be_bytes(secs), nanos))
+        } else {
+            None
+        }
+    }
+
+    /// Creates a `Duration` from a 16-byte big-endian representation with 4
+    /// bytes of trailing padding, returning [`None`] if the padding is not zero
+    /// or the nanoseconds are not less than one second.
+    ///
+    /// The first 12 bytes are read as by [`from_be_bytes`], and the last 4 bytes
+    /// must be zero.
+    ///
+    /// [`from_be_bytes`]: Duration::from_be_bytes
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_bytes)]
     /// use std::time::Duration;
     ///
-    /// assert_eq!(Duration::new(0, 500_000_001).saturating_mul(2), Duration::new(1, 2));
-    /// assert_eq!(Duration::new(u64::MAX - 1, 0).saturating_mul(2), Duration::MAX);
+    /// let bytes = [0, 0, 0, 0, 0, 0, 0x01, 0x02, 0, 0, 0x03, 0x04, 0, 0, 0, 0];
+    /// assert_eq!(Duration::from_be_bytes_padded(bytes), Some(Duration::new(0x0102, 0x0304)));
+    ///
+    /// let dirty_padding = [0, 0, 0, 0, 0, 0, 0x01, 0x02, 0, 0, 0x03, 0x04, 0, 0, 0, 1];
+    /// assert_eq!(Duration::from_be_bytes_padded(dirty_padding), None);
     /// ```
-    #[stable(feature = "duration_saturating_ops", since = "1.53.0")]
-    #[must_use = "this returns the result of the operation, \
-                  without modifying the original"]
+    #[unstable(feature = "duration_bytes", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_bytes", issue = "none")]
+    #[must_use]
     #[inline]
-    #[rustc_const_stable(feature = "duration_consts_2", since = "1.58.0")]
-    pub const fn saturating_mul(self, rhs: u32) -> Duration {
-        match self.checked_mul(rhs) {
-            Some(res) => res,
-            None => Duration::MAX,
+    pub const fn from_be_bytes_padded(bytes: [u8; 16]) -> Option<Duration> {
+        let mut unpadded = [0; 12];
+        let mut i = 0;
+        while i < 12 {
+            unpadded[i] = bytes[i];
+            i += 1;
+        }
+        while i < 16 {
+            if bytes[i] != 0 {
+                return None;
+            }
+            i += 1;
         }
+        Duration::from_be_bytes(unpadded)
     }
 
-    /// Checked `Duration` division. Computes `self / other`, returning [`None`]
-    /// if `other == 0`.
+    /// Returns an iterator over `self`, `self + step`, `self + 2 * step`, and so
+    /// on.
+    ///
+    /// The iterator ends when the next duration would overflow, so it never
+    /// panics. If `step` is zero, it yields `self` forever.
     ///
     /// # Examples
     ///
-    /// Basic usage:
+    /// ```
+    /// #![feature(duration_step_iter)]
+    /// use std::time::Duration;
+    ///
+    /// let ticks: Vec<_> = Duration::ZERO.step_iter(Duration::MILLISECOND).take(3).collect();
+    /// assert_eq!(ticks, [Duration::ZERO, Duration::from_millis(1), Duration::from_millis(2)]);
+    ///
+    /// let start = Duration::MAX - Duration::from_secs(1);
+    /// assert_eq!(start.step_iter(Duration::from_millis(600)).count(), 2);
+    /// ```
+    #[unstable(feature = "duration_step_iter", issue = "none")]
+    #[inline]
+    pub fn step_iter(self, step: Duration) -> StepIter {
+        StepIter { next: Some(self), step }
+    }
+
+    /// Restricts `self` to the interval `[min, max]`, or returns [`None`] if
+    /// `min > max`.
+    ///
+    /// Unlike [`Ord::clamp`], which panics on an invalid interval, this lets
+    /// callers handle bounds that come from untrusted input, such as a
+    /// configuration file.
+    ///
+    /// # Examples
     ///
     /// ```
+    /// #![feature(duration_clamp_to_range)]
     /// use std::time::Duration;
     ///
-    /// assert_eq!(Duration::new(2, 0).checked_div(2), Some(Duration::new(1, 0)));
-    /// assert_eq!(Duration::new(1, 0).checked_div(2), Some(Duration::new(0, 500_000_000)));
-    /// assert_eq!(Duration::new(2, 0).checked_div(0), None);
+    /// let min = Duration::from_secs(1);
+    /// let max = Duration::from_secs(5);
+    /// assert_eq!(Duration::ZERO.clamp_to_range(min, max), Some(min));
+    /// assert_eq!(Duration::from_secs(3).clamp_to_range(min, max), Some(Duration::from_secs(3)));
+    /// assert_eq!(Duration::from_secs(9).clamp_to_range(min, max), Some(max));
+    /// assert_eq!(Duration::from_secs(3).clamp_to_range(max, min), None);
     /// ```
-    #[stable(feature = "duration_checked_ops", since = "1.16.0")]
-    #[must_use = "this returns the result of the operation, \
-                  without modifying the original"]
+    #[unstable(feature = "duration_clamp_to_range", issue = "none")]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
     #[inline]
-    #[rustc_const_stable(feature = "duration_consts_2", since = "1.58.0")]
-    pub const fn checked_div(self, rhs: u32) -> Option<Duration> {
-        if rhs != 0 {
-            let secs = self.secs / (rhs as u64);
-            let carry = self.secs - secs * (rhs as u64);
-            let extra_nanos = carry * (NANOS_PER_SEC as u64) / (rhs as u64);
-            let nanos = self.nanos.0 / rhs + (extra_nanos as u32);
-            debug_assert!(nanos < NANOS_PER_SEC);
-            Some(Duration::new(secs, nanos))
-        } else {
-            None
+    pub fn clamp_to_range(self, min: Duration, max: Duration) -> Option<Duration> {
+        if min <= max { Some(self.clamp(min, max)) } else { None }
+    }
+
+    /// Multiplies `self` by the fraction `numerator / denominator`, returning
+    /// [`None`] if `denominator` is zero or the result overflows.
+    ///
+    /// The computation is exact, without any floating-point rounding, and the
+    /// result is rounded down to whole nanoseconds. Intermediate products may
+    /// exceed the range of `Duration` as long as the result does not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_pct)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::from_secs(10).pct(75, 100), Some(Duration::from_millis(7_500)));
+    /// assert_eq!(Duration::from_secs(1).pct(1, 3), Some(Duration::from_nanos(333_333_333)));
+    /// assert_eq!(Duration::MAX.pct(u64::MAX, u64::MAX), Some(Duration::MAX));
+    /// assert_eq!(Duration::MAX.pct(2, 1), None);
+    /// assert_eq!(Duration::from_secs(1).pct(1, 0), None);
+    /// ```
+    #[unstable(feature = "duration_pct", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_pct", issue = "none")]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub const fn pct(self, numerator: u64, denominator: u64) -> Option<Duration> {
+        if denominator == 0 {
+            return None;
+        }
+        let nanos = self.as_nanos();
+        let (quotient, remainder) = (nanos / denominator as u128, nanos % denominator as u128);
+        // `remainder < denominator`, so this cannot overflow.
+        let fraction = remainder * numerator as u128 / denominator as u128;
+        match quotient.checked_mul(numerator as u128) {
+            Some(nanos) => match nanos.checked_add(fraction) {
+                Some(nanos) => Duration::from_nanos_u128(nanos),
+                None => None,
+            },
+            None => None,
         }
     }
 
-    /// Returns the number of seconds contained by this `Duration` as `f64`.
+    /// Returns `true` if `self` is less than `other` by more than `tolerance`, that is, if
+    /// `self + tolerance < other`.
     ///
-    /// The returned value does include the fractional (nanosecond) part of the duration.
+    /// The sum saturates at [`Duration::MAX`] instead of overflowing.
     ///
     /// # Examples
+    ///
     /// ```
+    /// #![feature(duration_approx)]
     /// use std::time::Duration;
     ///
-    /// let dur = Duration::new(2, 700_000_000);
-    /// assert_eq!(dur.as_secs_f64(), 2.7);
+    /// let tolerance = Duration::from_millis(10);
+    /// assert!(Duration::from_millis(100).approx_lt(Duration::from_millis(120), tolerance));
+    /// assert!(!Duration::from_millis(100).approx_lt(Duration::from_millis(105), tolerance));
     /// ```
-    #[stable(feature = "duration_float", since = "1.38.0")]
+    #[unstable(feature = "duration_approx", issue = "none")]
     #[must_use]
     #[inline]
-    #[rustc_const_unstable(feature = "duration_consts_float", issue = "72440")]
-    pub const fn as_secs_f64(&self) -> f64 {
-        (self.secs as f64) + (self.nanos.0 as f64) / (NANOS_PER_SEC as f64)
+    pub fn approx_lt(self, other: Duration, tolerance: Duration) -> bool {
+        self.saturating_add(tolerance) < other
     }
 
-    /// Returns the number of seconds contained by this `Duration` as `f32`.
+    /// Returns `true` if `self` is less than `other` or exceeds it by at most
+    /// `tolerance`, that is, if `self <= other + tolerance`.
     ///
-    /// The returned value does include the fractional (nanosecond) part of the duration.
+    /// The sum saturates at [`Duration::MAX`] instead of overflowing.
     ///
     /// # Examples
+    ///
     /// ```
+    /// #![feature(duration_approx)]
     /// use std::time::Duration;
     ///
-    /// let dur = Duration::new(2, 700_000_000);
-    /// assert_eq!(dur.as_secs_f32(), 2.7);
+    /// let tolerance = Duration::from_millis(10);
+    /// assert!(Duration::from_millis(105).approx_le(Duration::from_millis(100), tolerance));
+    /// assert!(!Duration::from_millis(120).approx_le(Duration::from_millis(100), tolerance));
     /// ```
-    #[stable(feature = "duration_float", since = "1.38.0")]
+    #[unstable(feature = "duration_approx", issue = "none")]
     #[must_use]
     #[inline]
-    #[rustc_const_unstable(feature = "duration_consts_float", issue = "72440")]
-    pub const fn as_secs_f32(&self) -> f32 {
-        (self.secs as f32) + (self.nanos.0 as f32) / (NANOS_PER_SEC as f32)
+    pub fn approx_le(self, other: Duration, tolerance: Duration) -> bool {
+        self <= other.saturating_add(tolerance)
     }
 
-    /// Creates a new `Duration` from the specified number of seconds represented
-    /// as `f64`.
+    /// Returns `true` if `self` is greater than `other` by more than `tolerance`, that is, if
+    /// `self > other + tolerance`.
     ///
-    /// # Panics
-    /// This constructor will panic if `secs` is negative, overflows `Duration` or not finite.
+    /// The sum saturates at [`Duration::MAX`] instead of overflowing.
     ///
     /// # Examples
+    ///
     /// ```
+    /// #![feature(duration_approx)]
     /// use std::time::Duration;
     ///
-    /// let res = Duration::from_secs_f64(0.0);
-    /// assert_eq!(res, Duration::new(0, 0));
-    /// let res = Duration::from_secs_f64(1e-20);
-    /// assert_eq!(res, Duration::new(0, 0));
-    /// let res = Duration::from_secs_f64(4.2e-7);
-    /// assert_eq!(res, Duration::new(0, 420));
-    /// let res = Duration::from_secs_f64(2.7);
-    /// assert_eq!(res, Duration::new(2, 700_000_000));
-    /// let res = Duration::from_secs_f64(3e10);
-    /// assert_eq!(res, Duration::new(30_000_000_000, 0));
-    /// // subnormal float
-    /// let res = Duration::from_secs_f64(f64::from_bits(1));
-    /// assert_eq!(res, Duration::new(0, 0));
-    /// // conversion uses rounding
-    /// let res = Duration::from_secs_f64(0.999e-9);
-    /// assert_eq!(res, Duration::new(0, 1));
+    /// let tolerance = Duration::from_millis(10);
+    /// assert!(Duration::from_millis(120).approx_gt(Duration::from_millis(100), tolerance));
+    /// assert!(!Duration::from_millis(105).approx_gt(Duration::from_millis(100), tolerance));
     /// ```
-    #[stable(feature = "duration_float", since = "1.38.0")]
+    #[unstable(feature = "duration_approx", issue = "none")]
     #[must_use]
     #[inline]
-    #[rustc_const_unstable(feature = "duration_consts_float", issue = "72440")]
-    pub const fn from_secs_f64(secs: f64) -> Duration {
-        match Duration::try_from_secs_f64(secs) {
-            Ok(v) => v,
-            Err(e) => panic!("{}", e.description()),
-        }
+    pub fn approx_gt(self, other: Duration, tolerance: Duration) -> bool {
+        self > other.saturating_add(tolerance)
     }
 
-    /// Creates a new `Duration` from the specified number of seconds represented
-    /// as `f32`.
+    /// Returns `true` if `self` is greater than `other` or falls short of it by
+    /// at most `tolerance`, that is, if `self + tolerance >= other`.
     ///
-    /// # Panics
-    /// This constructor will panic if `secs` is negative, overflows `Duration` or not finite.
+    /// The sum saturates at [`Duration::MAX`] instead of overflowing.
     ///
     /// # Examples
+    ///
     /// ```
+    /// #![feature(duration_approx)]
     /// use std::time::Duration;
     ///
-    /// let res = Duration::from_secs_f32(0.0);
-    /// assert_eq!(res, Duration::new(0, 0));
-    /// let res = Duration::from_secs_f32(1e-20);
-    /// assert_eq!(res, Duration::new(0, 0));
-    /// let res = Duration::from_secs_f32(4.2e-7);
-    /// assert_eq!(res, Duration::new(0, 420));
-    /// let res = Duration::from_secs_f32(2.7);
-    /// assert_eq!(res, Duration::new(2, 700_000_048));
-    /// let res = Duration::from_secs_f32(3e10);
-    /// assert_eq!(res, Duration::new(30_000_001_024, 0));
-    /// // subnormal float
-    /// let res = Duration::from_secs_f32(f32::from_bits(1));
-    /// assert_eq!(res, Duration::new(0, 0));
-    /// // conversion uses rounding
-    /// let res = Duration::from_secs_f32(0.999e-9);
-    /// assert_eq!(res, Duration::new(0, 1));
+    /// let tolerance = Duration::from_millis(10);
+    /// assert!(Duration::from_millis(95).approx_ge(Duration::from_millis(100), tolerance));
+    /// assert!(!Duration::from_millis(80).approx_ge(Duration::from_millis(100), tolerance));
     /// ```
-    #[stable(feature = "duration_float", since = "1.38.0")]
+    #[unstable(feature = "duration_approx", issue = "none")]
     #[must_use]
     #[inline]
-    #[rustc_const_unstable(feature = "duration_consts_float", issue = "72440")]
-    pub const fn from_secs_f32(secs: f32) -> Duration {
-        match Duration::try_from_secs_f32(secs) {
-            Ok(v) => v,
-            Err(e) => panic!("{}", e.description()),
+    pub fn approx_ge(self, other: Duration, tolerance: Duration) -> bool {
+        self.saturating_add(tolerance) >= other
+    }
+
+    /// Compares `self` and `other`, treating them as equal if they differ by at
+    /// most `tolerance`.
+    ///
+    /// Returns [`Ordering::Equal`] if `self.abs_diff(other) <= tolerance`, and
+    /// otherwise [`Ordering::Less`] or [`Ordering::Greater`] as [`Ord::cmp`]
+    /// would.
+    ///
+    /// Note that this is not a total order, since equality within a tolerance is
+    /// not transitive: with a tolerance of 10ms, 0ms equals 10ms and 10ms equals
+    /// 20ms, but 0ms is less than 20ms. It should therefore not be used to sort
+    /// durations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_approx)]
+    /// use std::cmp::Ordering;
+    /// use std::time::Duration;
+    ///
+    /// let ms = Duration::from_millis;
+    /// let tolerance = ms(10);
+    /// assert_eq!(ms(95).compare_with_tolerance(ms(100), tolerance), Ordering::Equal);
+    /// assert_eq!(ms(110).compare_with_tolerance(ms(100), tolerance), Ordering::Equal);
+    /// assert_eq!(ms(80).compare_with_tolerance(ms(100), tolerance), Ordering::Less);
+    /// assert_eq!(ms(120).compare_with_tolerance(ms(100), tolerance), Ordering::Greater);
+    /// ```
+    #[unstable(feature = "duration_approx", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub fn compare_with_tolerance(self, other: Duration, tolerance: Duration) -> Ordering {
+        if self.abs_diff(other) <= tolerance { Ordering::Equal } else { self.cmp(&other) }
+    }
+
+    /// Returns how many whole times `self` fits into `target`, together with
+    /// the total duration of that many repetitions of `self`.
+    ///
+    /// The returned count `n` is the largest integer for which `self * n` does
+    /// not exceed `target`. It is a `u128`, since a short `self` can fit into a
+    /// long `target` more than [`u64::MAX`] times. Since every count fits into
+    /// `target` if `self` is zero, a zero `self` returns `(Duration::ZERO, 0)`
+    /// instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_scale_to_fit)]
+    /// use std::time::Duration;
+    ///
+    /// let slot = Duration::from_millis(300);
+    /// let budget = Duration::SECOND;
+    /// assert_eq!(slot.scale_to_fit(budget), (Duration::from_millis(900), 3));
+    /// assert_eq!(budget.scale_to_fit(slot), (Duration::ZERO, 0));
+    /// assert_eq!(Duration::ZERO.scale_to_fit(budget), (Duration::ZERO, 0));
+    /// ```
+    #[unstable(feature = "duration_scale_to_fit", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_scale_to_fit", issue = "none")]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub const fn scale_to_fit(self, target: Duration) -> (Duration, u128) {
+        if self.is_zero() {
+            return (Duration::ZERO, 0);
         }
+        let target = target.as_nanos();
+        let n = target / self.as_nanos();
+        let total = target - target % self.as_nanos();
+        // `total` is at most `target`, so the seconds fit in a `u64`.
+        let total = Duration::new(
+            (total / NANOS_PER_SEC as u128) as u64,
+            (total % NANOS_PER_SEC as u128) as u32,
+        );
+        (total, n)
     }
 
-    /// Multiplies `Duration` by `f64`.
+    /// Returns the exact ratio `self / rhs` as a `(numerator, denominator)`
+    /// pair of nanosecond counts in lowest terms.
+    ///
+    /// Unlike [`div_duration_f64`], this does not lose any precision. A zero
+    /// `self` gives `(0, 1)`.
     ///
     /// # Panics
-    /// This method will panic if result is negative, overflows `Duration` or not finite.
+    ///
+    /// Panics if `rhs` is zero.
     ///
     /// # Examples
+    ///
     /// ```
+    /// #![feature(duration_ratio)]
     /// use std::time::Duration;
     ///
-    /// let dur = Duration::new(2, 700_000_000);
-    /// assert_eq!(dur.mul_f64(3.14), Duration::new(8, 478_000_000));
-    /// assert_eq!(dur.mul_f64(3.14e5), Duration::new(847_800, 0));
+    /// let a = Duration::from_nanos(20_833); // One sample period at 48 kHz.
+    /// let b = Duration::from_nanos(22_676); // One sample period at 44.1 kHz.
+    /// assert_eq!(a.ratio_u128(b), (20_833, 22_676));
+    /// assert_eq!(Duration::from_secs(3).ratio_u128(Duration::from_millis(1_500)), (2, 1));
+    /// assert_eq!(Duration::ZERO.ratio_u128(Duration::SECOND), (0, 1));
     /// ```
-    #[stable(feature = "duration_float", since = "1.38.0")]
-    #[must_use = "this returns the result of the operation, \
-                  without modifying the original"]
+    ///
+    /// [`div_duration_f64`]: Duration::div_duration_f64
+    #[unstable(feature = "duration_ratio", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_ratio", issue = "none")]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
     #[inline]
-    #[rustc_const_unstable(feature = "duration_consts_float", issue = "72440")]
-    pub const fn mul_f64(self, rhs: f64) -> Duration {
-        Duration::from_secs_f64(rhs * self.as_secs_f64())
+    #[track_caller]
+    pub const fn ratio_u128(self, rhs: Duration) -> (u128, u128) {
+        if rhs.is_zero() {
+            panic!("divide by zero error when computing the ratio of durations");
+        }
+        let (numerator, denominator) = (self.as_nanos(), rhs.as_nanos());
+        let gcd = gcd(numerator, denominator);
+        (numerator / gcd, denominator / gcd)
     }
 
-    /// Multiplies `Duration` by `f32`.
+    /// Returns the greatest common divisor of `self` and `other`: the longest
+    /// duration that both are a whole multiple of.
     ///
-    /// # Panics
-    /// This method will panic if result is negative, overflows `Duration` or not finite.
+    /// [`Duration::ZERO`] is a multiple of every duration, so the greatest common
+    /// divisor of zero and `d` is `d`, and the result is only zero if both
+    /// `self` and `other` are zero.
     ///
     /// # Examples
+    ///
     /// ```
+    /// #![feature(duration_gcd)]
     /// use std::time::Duration;
     ///
-    /// let dur = Duration::new(2, 700_000_000);
-    /// assert_eq!(dur.mul_f32(3.14), Duration::new(8, 478_000_641));
-    /// assert_eq!(dur.mul_f32(3.14e5), Duration::new(847800, 0));
+    /// let a = Duration::from_millis(100);
+    /// let b = Duration::from_millis(150);
+    /// assert_eq!(a.gcd(b), Duration::from_millis(50));
+    /// assert_eq!(a.gcd(Duration::ZERO), a);
+    /// assert_eq!(Duration::ZERO.gcd(Duration::ZERO), Duration::ZERO);
     /// ```
-    #[stable(feature = "duration_float", since = "1.38.0")]
-    #[must_use = "this returns the result of the operation, \
-                  without modifying the original"]
+    #[unstable(feature = "duration_gcd", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_gcd", issue = "none")]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
     #[inline]
-    #[rustc_const_unstable(feature = "duration_consts_float", issue = "72440")]
-    pub const fn mul_f32(self, rhs: f32) -> Duration {
-        Duration::from_secs_f32(rhs * self.as_secs_f32())
+    pub const fn gcd(self, other: Duration) -> Duration {
+        let nanos = gcd(self.as_nanos(), other.as_nanos());
+        // The result is at most `max(self, other)`, so the seconds fit in a `u64`.
+        Duration::new(
+            (nanos / NANOS_PER_SEC as u128) as u64,
+            (nanos % NANOS_PER_SEC as u128) as u32,
+        )
+    }
+
+    /// Returns the least common multiple of `self` and `other`: the shortest
+    /// nonzero duration that is a whole multiple of both, or [`None`] if it
+    /// overflows.
+    ///
+    /// If either `self` or `other` is zero, the result is [`Duration::ZERO`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_lcm)]
+    /// use std::time::Duration;
+    ///
+    /// let a = Duration::from_millis(40);
+    /// let b = Duration::from_millis(60);
+    /// assert_eq!(a.lcm(b), Some(Duration::from_millis(120)));
+    /// assert_eq!(a.lcm(Duration::ZERO), Some(Duration::ZERO));
+    /// assert_eq!(Duration::MAX.lcm(Duration::MAX - Duration::NANOSECOND), None);
+    /// ```
+    #[unstable(feature = "duration_lcm", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_lcm", issue = "none")]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub const fn lcm(self, other: Duration) -> Option<Duration> {
+        let (a, b) = (self.as_nanos(), other.as_nanos());
+        if a == 0 || b == 0 {
+            return Some(Duration::ZERO);
+        }
+        // Dividing first keeps the intermediate result no larger than the
+        // least common multiple itself.
+        match (a / gcd(a, b)).checked_mul(b) {
+            Some(nanos) => Duration::from_nanos_u128(nanos),
+            None => None,
+        }
     }
 
-    /// Divide `Duration` by `f64`.
+    /// Returns `true` if `self` is a whole multiple of `divisor`, that is, if
+    /// `divisor` fits into `self` a whole number of times.
+    ///
+    /// [`Duration::ZERO`] is a multiple of every duration.
     ///
     /// # Panics
-    /// This method will panic if result is negative, overflows `Duration` or not finite.
+    ///
+    /// Panics if `divisor` is zero.
     ///
     /// # Examples
+    ///
     /// ```
+    /// #![feature(duration_is_multiple_of)]
     /// use std::time::Duration;
     ///
-    /// let dur = Duration::new(2, 700_000_000);
-    /// assert_eq!(dur.div_f64(3.14), Duration::new(0, 859_872_611));
-    /// assert_eq!(dur.div_f64(3.14e5), Duration::new(0, 8_599));
+    /// assert!(Duration::from_secs(6).is_multiple_of(Duration::from_secs(2)));
+    /// assert!(!Duration::from_secs(6).is_multiple_of(Duration::from_secs(4)));
+    /// assert!(Duration::ZERO.is_multiple_of(Duration::from_secs(4)));
     /// ```
-    #[stable(feature = "duration_float", since = "1.38.0")]
-    #[must_use = "this returns the result of the operation, \
-                  without modifying the original"]
+    #[unstable(feature = "duration_is_multiple_of", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_is_multiple_of", issue = "none")]
+    #[must_use]
     #[inline]
-    #[rustc_const_unstable(feature = "duration_consts_float", issue = "72440")]
-    pub const fn div_f64(self, rhs: f64) -> Duration {
-        Duration::from_secs_f64(self.as_secs_f64() / rhs)
+    #[track_caller]
+    pub const fn is_multiple_of(self, divisor: Duration) -> bool {
+        if divisor.is_zero() {
+            panic!("divide by zero error when checking for a multiple of a duration");
+        }
+        self.as_nanos() % divisor.as_nanos() == 0
     }
 
-    /// Divide `Duration` by `f32`.
+    /// Rounds this `Duration` to the nearest whole multiple of `multiple`.
+    ///
+    /// A duration exactly halfway between two multiples is rounded up.
     ///
     /// # Panics
-    /// This method will panic if result is negative, overflows `Duration` or not finite.
+    ///
+    /// Panics if `multiple` is zero, or if rounding up overflows `Duration`.
     ///
     /// # Examples
+    ///
     /// ```
+    /// #![feature(duration_rounding)]
     /// use std::time::Duration;
     ///
-    /// let dur = Duration::new(2, 700_000_000);
-    /// // note that due to rounding errors result is slightly
-    /// // different from 0.859_872_611
-    /// assert_eq!(dur.div_f32(3.14), Duration::new(0, 859_872_580));
-    /// assert_eq!(dur.div_f32(3.14e5), Duration::new(0, 8_599));
+    /// let ms = Duration::from_millis;
+    /// assert_eq!(ms(750).round_to_nearest_multiple(Duration::SECOND), Duration::SECOND);
+    /// assert_eq!(ms(1_250).round_to_nearest_multiple(Duration::SECOND), Duration::SECOND);
+    /// assert_eq!(ms(1_500).round_to_nearest_multiple(Duration::SECOND), ms(2_000));
+    /// assert_eq!(ms(1_234).round_to_nearest_multiple(ms(100)), ms(1_200));
     /// ```
-    #[stable(feature = "duration_float", since = "1.38.0")]
+    #[unstable(feature = "duration_rounding", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_rounding", issue = "none")]
     #[must_use = "this returns the result of the operation, \
                   without modifying the original"]
     #[inline]
-    #[rustc_const_unstable(feature = "duration_consts_float", issue = "72440")]
-    pub const fn div_f32(self, rhs: f32) -> Duration {
-        Duration::from_secs_f32(self.as_secs_f32() / rhs)
+    #[track_caller]
+    pub const fn round_to_nearest_multiple(self, multiple: Duration) -> Duration {
+        if multiple.is_zero() {
+            panic!("divide by zero error when rounding to a multiple of a duration");
+        }
+        let (nanos, multiple) = (self.as_nanos(), multiple.as_nanos());
+        let remainder = nanos % multiple;
+        // Both fit in a `Duration`, so neither the doubled remainder nor the
+        // rounded up value can overflow a `u128`.
+        let rounded = if 2 * remainder >= multiple {
+            nanos - remainder + multiple
+        } else {
+            nanos - remainder
+        };
+        match Duration::from_nanos_u128(rounded) {
+            Some(duration) => duration,
+            None => panic!("overflow when rounding a duration to a multiple of a duration"),
+        }
     }
 
-    /// Divide `Duration` by `Duration` and return `f64`.
+    /// Divides `total` evenly among `items`, returning [`None`] if `items` is
+    /// zero.
+    ///
+    /// This is the same as [`total.checked_div(items)`], named after the common
+    /// use of distributing a time budget over threads, requests or time slices.
+    /// The share is rounded down to whole nanoseconds.
     ///
     /// # Examples
+    ///
     /// ```
-    /// #![feature(div_duration)]
+    /// #![feature(duration_spread)]
     /// use std::time::Duration;
     ///
-    /// let dur1 = Duration::new(2, 700_000_000);
-    /// let dur2 = Duration::new(5, 400_000_000);
-    /// assert_eq!(dur1.div_duration_f64(dur2), 0.5);
+    /// let share = Duration::spread(3, Duration::from_secs(10));
+    /// assert_eq!(share, Some(Duration::from_nanos(3_333_333_333)));
+    /// assert_eq!(Duration::spread(0, Duration::from_secs(10)), None);
     /// ```
-    #[unstable(feature = "div_duration", issue = "63139")]
-    #[must_use = "this returns the result of the operation, \
-                  without modifying the original"]
+    ///
+    /// [`total.checked_div(items)`]: Duration::checked_div
+    #[unstable(feature = "duration_spread", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_spread", issue = "none")]
+    #[must_use]
     #[inline]
-    #[rustc_const_unstable(feature = "duration_consts_float", issue = "72440")]
-    pub const fn div_duration_f64(self, rhs: Duration) -> f64 {
-        self.as_secs_f64() / rhs.as_secs_f64()
+    pub const fn spread(items: u32, total: Duration) -> Option<Duration> {
+        total.checked_div(items)
     }
+}
 
-    /// Divide `Duration` by `Duration` and return `f32`.
+#[stable(feature = "duration", since = "1.3.0")]
+impl Default for Duration {
+    /// Returns the zero duration, [`Duration::ZERO`].
     ///
     /// # Examples
+    ///
     /// ```
-    /// #![feature(div_duration)]
     /// use std::time::Duration;
     ///
-    /// let dur1 = Duration::new(2, 700_000_000);
-    /// let dur2 = Duration::new(5, 400_000_000);
-    /// assert_eq!(dur1.div_duration_f32(dur2), 0.5);
+    /// assert_eq!(Duration::default(), Duration::ZERO);
     /// ```
-    #[unstable(feature = "div_duration", issue = "63139")]
-    #[must_use = "this returns the result of the operation, \
-                  without modifying the original"]
     #[inline]
-    #[rustc_const_unstable(feature = "duration_consts_float", issue = "72440")]
-    pub const fn div_duration_f32(self, rhs: Duration) -> f32 {
-        self.as_secs_f32() / rhs.as_secs_f32()
+    fn default() -> Duration {
+        Duration::ZERO
     }
 }
 
@@ -985,43 +3874,17 @@ fn div_assign(&mut self, rhs: u32) {
     }
 }
 
-macro_rules! sum_durations {
-    ($iter:expr) => {{
-        let mut total_secs: u64 = 0;
-        let mut total_nanos: u64 = 0;
-
-        for entry in $iter {
-            total_secs =
-                total_secs.checked_add(entry.secs).expect("overflow in iter::sum over durations");
-            total_nanos = match total_nanos.checked_add(entry.nanos.0 as u64) {
-                Some(n) => n,
-                None => {
-                    total_secs = total_secs
-                        .checked_add(total_nanos / NANOS_PER_SEC as u64)
-                        .expect("overflow in iter::sum over durations");
-                    (total_nanos % NANOS_PER_SEC as u64) + entry.nanos.0 as u64
-                }
-            };
-        }
-        total_secs = total_secs
-            .checked_add(total_nanos / NANOS_PER_SEC as u64)
-            .expect("overflow in iter::sum over durations");
-        total_nanos = total_nanos % NANOS_PER_SEC as u64;
-        Duration::new(total_secs, total_nanos as u32)
-    }};
-}
-
 #[stable(feature = "duration_sum", since = "1.16.0")]
 impl Sum for Duration {
     fn sum<I: Iterator<Item = Duration>>(iter: I) -> Duration {
-        sum_durations!(iter)
+        Duration::checked_sum_iter(iter).expect("overflow in iter::sum over durations")
     }
 }
 
 #[stable(feature = "duration_sum", since = "1.16.0")]
 impl<'a> Sum<&'a Duration> for Duration {
     fn sum<I: Iterator<Item = &'a Duration>>(iter: I) -> Duration {
-        sum_durations!(iter)
+        Duration::checked_sum_iter(iter.copied()).expect("overflow in iter::sum over durations")
     }
 }
 
@@ -1187,32 +4050,410 @@ fn fmt_decimal(
             }
         }
 
-        // Print leading '+' sign if requested
-        let prefix = if f.sign_plus() { "+" } else { "" };
+        // Print leading '+' sign if requested
+        let prefix = if f.sign_plus() { "+" } else { "" };
+
+        // A zero duration is printed in seconds, as `0s`.
+        if self.secs > 0 || self.nanos.0 == 0 {
+            fmt_decimal(f, self.secs, self.nanos.0, NANOS_PER_SEC / 10, prefix, "s")
+        } else if self.nanos.0 >= NANOS_PER_MILLI {
+            fmt_decimal(
+                f,
+                (self.nanos.0 / NANOS_PER_MILLI) as u64,
+                self.nanos.0 % NANOS_PER_MILLI,
+                NANOS_PER_MILLI / 10,
+                prefix,
+                "ms",
+            )
+        } else if self.nanos.0 >= NANOS_PER_MICRO {
+            fmt_decimal(
+                f,
+                (self.nanos.0 / NANOS_PER_MICRO) as u64,
+                self.nanos.0 % NANOS_PER_MICRO,
+                NANOS_PER_MICRO / 10,
+                prefix,
+                "µs",
+            )
+        } else {
+            fmt_decimal(f, self.nanos.0 as u64, 0, 1, prefix, "ns")
+        }
+    }
+}
+
+/// A half-open range of [`Duration`]s, `start..end`.
+///
+/// The range contains all durations `d` with `start <= d < end`, and is empty if
+/// `start >= end`. It can describe APIs that accept a duration within some
+/// bounds, such as a retry delay of at least 100 milliseconds but less than 2
+/// seconds.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(duration_range)]
+/// use std::time::{Duration, DurationRange};
+///
+/// let retry = DurationRange { start: Duration::from_millis(100), end: Duration::from_secs(2) };
+/// assert!(retry.contains(Duration::from_millis(100)));
+/// assert!(!retry.contains(Duration::from_secs(2)));
+/// assert_eq!(retry.len(), Duration::from_millis(1_900));
+/// assert_eq!(retry.clamp(Duration::ZERO), Duration::from_millis(100));
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[unstable(feature = "duration_range", issue = "none")]
+pub struct DurationRange {
+    /// The lower bound of the range (inclusive).
+    #[unstable(feature = "duration_range", issue = "none")]
+    pub start: Duration,
+    /// The upper bound of the range (exclusive).
+    #[unstable(feature = "duration_range", issue = "none")]
+    pub end: Duration,
+}
+
+#[unstable(feature = "duration_range", issue = "none")]
+impl fmt::Debug for DurationRange {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.start.fmt(fmt)?;
+        write!(fmt, "..")?;
+        self.end.fmt(fmt)
+    }
+}
+
+impl DurationRange {
+    /// Returns `true` if `duration` is contained in the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_range)]
+    /// use std::time::{Duration, DurationRange};
+    ///
+    /// let range = DurationRange { start: Duration::from_secs(1), end: Duration::from_secs(2) };
+    /// assert!(!range.contains(Duration::from_millis(999)));
+    /// assert!(range.contains(Duration::from_secs(1)));
+    /// assert!(range.contains(Duration::from_millis(1_999)));
+    /// assert!(!range.contains(Duration::from_secs(2)));
+    /// ```
+    #[unstable(feature = "duration_range", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub fn contains(&self, duration: Duration) -> bool {
+        self.start <= duration && duration < self.end
+    }
+
+    /// Returns the length of the range, `end - start`.
+    ///
+    /// The length of an empty range is [`Duration::ZERO`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_range)]
+    /// use std::time::{Duration, DurationRange};
+    ///
+    /// let range = DurationRange { start: Duration::from_secs(1), end: Duration::from_secs(3) };
+    /// assert_eq!(range.len(), Duration::from_secs(2));
+    ///
+    /// let empty = DurationRange { start: Duration::from_secs(3), end: Duration::from_secs(1) };
+    /// assert_eq!(empty.len(), Duration::ZERO);
+    /// ```
+    #[unstable(feature = "duration_range", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> Duration {
+        self.end.saturating_sub(self.start)
+    }
+
+    /// Returns `true` if the range contains no durations.
+    ///
+    /// The range is empty if `start >= end`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_range)]
+    /// use std::time::{Duration, DurationRange};
+    ///
+    /// let range = DurationRange { start: Duration::from_secs(1), end: Duration::from_secs(2) };
+    /// assert!(!range.is_empty());
+    ///
+    /// let empty = DurationRange { start: Duration::from_secs(2), end: Duration::from_secs(2) };
+    /// assert!(empty.is_empty());
+    /// ```
+    #[unstable(feature = "duration_range", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    /// Returns the duration in the range that is closest to `duration`.
+    ///
+    /// This is `duration` itself if the range contains it, `start` if `duration`
+    /// is smaller than `start`, and the largest duration below `end` otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_range)]
+    /// use std::time::{Duration, DurationRange};
+    ///
+    /// let range = DurationRange { start: Duration::from_secs(1), end: Duration::from_secs(2) };
+    /// assert_eq!(range.clamp(Duration::ZERO), Duration::from_secs(1));
+    /// assert_eq!(range.clamp(Duration::from_millis(1_500)), Duration::from_millis(1_500));
+    /// assert_eq!(range.clamp(Duration::from_secs(5)), Duration::new(1, 999_999_999));
+    /// ```
+    #[unstable(feature = "duration_range", issue = "none")]
+    #[must_use]
+    #[track_caller]
+    pub fn clamp(&self, duration: Duration) -> Duration {
+        assert!(!self.is_empty(), "cannot clamp to an empty DurationRange");
+        duration.clamp(self.start, self.end - Duration::NANOSECOND)
+    }
+}
+
+/// An iterator over evenly spaced [`Duration`]s.
+///
+/// This `struct` is created by [`Duration::step_iter`]. See its documentation
+/// for more.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "duration_step_iter", issue = "none")]
+pub struct StepIter {
+    next: Option<Duration>,
+    step: Duration,
+}
+
+#[unstable(feature = "duration_step_iter", issue = "none")]
+impl Iterator for StepIter {
+    type Item = Duration;
+
+    #[inline]
+    fn next(&mut self) -> Option<Duration> {
+        let current = self.next?;
+        self.next = current.checked_add(self.step);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.next {
+            None => (0, Some(0)),
+            Some(_) if self.step.is_zero() => (usize::MAX, None),
+            Some(next) => {
+                let remaining = (Duration::MAX - next).as_nanos() / self.step.as_nanos() + 1;
+                match usize::try_from(remaining) {
+                    Ok(remaining) => (remaining, Some(remaining)),
+                    Err(_) => (usize::MAX, None),
+                }
+            }
+        }
+    }
+}
+
+#[unstable(feature = "duration_step_iter", issue = "none")]
+impl FusedIterator for StepIter {}
+
+/// A [`Duration`] with a sign, for differences between points in time that
+/// may be negative.
+///
+/// A `SignedDuration` is a `Duration` magnitude together with a sign. Zero has
+/// no sign, so `-SignedDuration::ZERO == SignedDuration::ZERO`.
+///
+/// `Duration` itself is unsigned and does not implement [`Neg`]; negating one
+/// is done by converting it into a `SignedDuration` first:
+///
+/// ```compile_fail,E0600
+/// use std::time::Duration;
+///
+/// let _ = -Duration::SECOND;
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// #![feature(signed_duration)]
+/// use std::time::{Duration, SignedDuration};
+///
+/// let one = SignedDuration::from(Duration::SECOND);
+/// assert!(!one.is_negative());
+/// assert!((-one).is_negative());
+/// assert!(-one < SignedDuration::ZERO);
+/// assert_eq!(-(-one), one);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+#[unstable(feature = "signed_duration", issue = "none")]
+pub struct SignedDuration {
+    magnitude: Duration,
+    // Always `false` if `magnitude` is zero.
+    negative: bool,
+}
+
+impl SignedDuration {
+    /// A signed duration of zero time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(signed_duration)]
+    /// use std::time::{Duration, SignedDuration};
+    ///
+    /// assert_eq!(SignedDuration::ZERO, SignedDuration::from(Duration::ZERO));
+    /// ```
+    #[unstable(feature = "signed_duration", issue = "none")]
+    pub const ZERO: SignedDuration = SignedDuration { magnitude: Duration::ZERO, negative: false };
+
+    /// Returns `true` if this signed duration is less than zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(signed_duration)]
+    /// use std::time::{Duration, SignedDuration};
+    ///
+    /// assert!(!SignedDuration::from(Duration::SECOND).is_negative());
+    /// assert!((-SignedDuration::from(Duration::SECOND)).is_negative());
+    /// assert!(!(-SignedDuration::ZERO).is_negative());
+    /// ```
+    #[unstable(feature = "signed_duration", issue = "none")]
+    #[rustc_const_unstable(feature = "signed_duration", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns the magnitude of this signed duration as an unsigned [`Duration`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(signed_duration)]
+    /// use std::time::{Duration, SignedDuration};
+    ///
+    /// assert_eq!(SignedDuration::from(Duration::SECOND).abs(), Duration::SECOND);
+    /// assert_eq!((-SignedDuration::from(Duration::SECOND)).abs(), Duration::SECOND);
+    /// ```
+    #[unstable(feature = "signed_duration", issue = "none")]
+    #[rustc_const_unstable(feature = "signed_duration", issue = "none")]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub const fn abs(self) -> Duration {
+        self.magnitude
+    }
+
+    /// Returns a number representing the sign of this signed duration.
+    ///
+    /// - `0` if the signed duration is zero
+    /// - `1` if the signed duration is positive
+    /// - `-1` if the signed duration is negative
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(signed_duration)]
+    /// use std::time::{Duration, SignedDuration};
+    ///
+    /// assert_eq!(SignedDuration::from(Duration::SECOND).signum(), 1);
+    /// assert_eq!(SignedDuration::ZERO.signum(), 0);
+    /// assert_eq!((-SignedDuration::from(Duration::SECOND)).signum(), -1);
+    /// ```
+    #[unstable(feature = "signed_duration", issue = "none")]
+    #[rustc_const_unstable(feature = "signed_duration", issue = "none")]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub const fn signum(self) -> i32 {
+        if self.negative {
+            -1
+        } else if self.magnitude.is_zero() {
+            0
+        } else {
+            1
+        }
+    }
+}
+
+#[unstable(feature = "signed_duration", issue = "none")]
+impl From<Duration> for SignedDuration {
+    /// Converts a `Duration` into a non-negative `SignedDuration` with the same
+    /// magnitude.
+    #[inline]
+    fn from(duration: Duration) -> SignedDuration {
+        SignedDuration { magnitude: duration, negative: false }
+    }
+}
+
+#[unstable(feature = "signed_duration", issue = "none")]
+impl Neg for SignedDuration {
+    type Output = SignedDuration;
+
+    #[inline]
+    fn neg(self) -> SignedDuration {
+        SignedDuration {
+            magnitude: self.magnitude,
+            negative: !self.negative && !self.magnitude.is_zero(),
+        }
+    }
+}
+
+#[unstable(feature = "signed_duration", issue = "none")]
+impl PartialOrd for SignedDuration {
+    #[inline]
+    fn partial_cmp(&self, other: &SignedDuration) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[unstable(feature = "signed_duration", issue = "none")]
+impl Ord for SignedDuration {
+    fn cmp(&self, other: &SignedDuration) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+/// The error returned by [`Duration::try_sub`] when the result of the
+/// subtraction would be negative.
+///
+/// # Example
+///
+/// ```
+/// #![feature(duration_try_sub)]
+/// use std::time::Duration;
+///
+/// if let Err(e) = Duration::from_secs(1).try_sub(Duration::from_secs(3)) {
+///     println!("Failed subtraction: {e}");
+///     assert_eq!(e.magnitude(), Duration::from_secs(2));
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[unstable(feature = "duration_try_sub", issue = "none")]
+pub struct DurationUnderflowError {
+    magnitude: Duration,
+}
+
+impl DurationUnderflowError {
+    /// Returns how far below zero the result of the subtraction would have been.
+    #[unstable(feature = "duration_try_sub", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_try_sub", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn magnitude(&self) -> Duration {
+        self.magnitude
+    }
+}
 
-        if self.secs > 0 {
-            fmt_decimal(f, self.secs, self.nanos.0, NANOS_PER_SEC / 10, prefix, "s")
-        } else if self.nanos.0 >= NANOS_PER_MILLI {
-            fmt_decimal(
-                f,
-                (self.nanos.0 / NANOS_PER_MILLI) as u64,
-                self.nanos.0 % NANOS_PER_MILLI,
-                NANOS_PER_MILLI / 10,
-                prefix,
-                "ms",
-            )
-        } else if self.nanos.0 >= NANOS_PER_MICRO {
-            fmt_decimal(
-                f,
-                (self.nanos.0 / NANOS_PER_MICRO) as u64,
-                self.nanos.0 % NANOS_PER_MICRO,
-                NANOS_PER_MICRO / 10,
-                prefix,
-                "µs",
-            )
-        } else {
-            fmt_decimal(f, self.nanos.0 as u64, 0, 1, prefix, "ns")
-        }
+#[unstable(feature = "duration_try_sub", issue = "none")]
+impl fmt::Display for DurationUnderflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "duration underflowed by {:?}", self.magnitude)
     }
 }
 
@@ -1491,4 +4732,737 @@ pub const fn try_from_secs_f64(secs: f64) -> Result<Duration, TryFromFloatSecsEr
             double_ty = u128,
         )
     }
+
+    /// Creates a new `Duration` from the specified number of nanoseconds
+    /// represented as `f64`, returning [`None`] if it is negative, not finite,
+    /// or overflows `Duration`.
+    ///
+    /// The number of nanoseconds is converted to seconds and rounded in the
+    /// same way as by [`Duration::try_from_secs_f64`].
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_from_nanos_f64)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::checked_from_nanos_f64(2_500.0), Some(Duration::from_nanos(2_500)));
+    /// assert_eq!(Duration::checked_from_nanos_f64(1e9), Some(Duration::SECOND));
+    /// assert_eq!(Duration::checked_from_nanos_f64(-1.0), None);
+    /// assert_eq!(Duration::checked_from_nanos_f64(f64::NAN), None);
+    /// assert_eq!(Duration::checked_from_nanos_f64(f64::INFINITY), None);
+    /// assert_eq!(Duration::checked_from_nanos_f64(1e30), None);
+    /// ```
+    #[unstable(feature = "duration_from_nanos_f64", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_from_nanos_f64", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn checked_from_nanos_f64(nanos: f64) -> Option<Duration> {
+        match Duration::try_from_secs_f64(nanos / (NANOS_PER_SEC as f64)) {
+            Ok(duration) => Some(duration),
+            Err(_) => None,
+        }
+    }
+
+    /// Creates a new `Duration` from a number of milliseconds represented as
+    /// `f64`, as returned by JavaScript's `Date.now()` and `performance.now()`,
+    /// returning [`None`] if it is negative, not finite, or overflows `Duration`.
+    ///
+    /// The result is rounded to the nearest nanosecond, with halfway cases
+    /// rounded up. The whole milliseconds are converted exactly, so that only
+    /// the fractional part is affected by rounding.
+    /// [`Duration::to_javascript_ms`] converts in the other direction.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_javascript)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::from_javascript_ms(1.5), Some(Duration::from_micros(1_500)));
+    /// assert_eq!(Duration::from_javascript_ms(0.0000004), Some(Duration::ZERO));
+    /// assert_eq!(Duration::from_javascript_ms(0.0000006), Some(Duration::NANOSECOND));
+    /// assert_eq!(Duration::from_javascript_ms(-1.0), None);
+    /// assert_eq!(Duration::from_javascript_ms(f64::NAN), None);
+    /// ```
+    #[unstable(feature = "duration_javascript", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_javascript", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn from_javascript_ms(ms: f64) -> Option<Duration> {
+        // This also rejects NaN, for which every comparison is false.
+        if !(ms >= 0.0 && ms < f64::INFINITY) {
+            return None;
+        }
+        // Truncating a finite `f64` gives another `f64`, so `ms - whole as f64`
+        // is exact. Casts saturate, and a saturated `whole` overflows below.
+        let whole = ms as u128;
+        let fraction = ms - whole as f64;
+        let sub_milli_nanos = (fraction * NANOS_PER_MILLI as f64 + 0.5) as u128;
+        match whole.checked_mul(NANOS_PER_MILLI as u128) {
+            Some(nanos) => Duration::from_nanos_u128(nanos.saturating_add(sub_milli_nanos)),
+            None => None,
+        }
+    }
+
+    /// Returns the number of milliseconds contained by this `Duration` as
+    /// `f64`, the representation used by JavaScript's `Date.now()` and
+    /// `performance.now()`.
+    ///
+    /// This is the same as [`Duration::into_total_millis_f64`].
+    /// [`Duration::from_javascript_ms`] converts in the other direction.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_javascript)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::from_micros(1_500).to_javascript_ms(), 1.5);
+    /// let now = Duration::new(1_700_000_000, 123_500_000);
+    /// assert_eq!(now.to_javascript_ms(), 1_700_000_000_123.5);
+    /// ```
+    #[unstable(feature = "duration_javascript", issue = "none")]
+    #[rustc_const_unstable(feature = "duration_javascript", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn to_javascript_ms(self) -> f64 {
+        self.into_total_millis_f64()
+    }
+}
+
+/// An error which can be returned when parsing a [`Duration`] from a string.
+///
+/// This error is used as the error type for [`Duration::from_hex_str`],
+/// [`Duration::parse_human_readable`], [`Duration::from_str_flexible`] and
+/// [`Duration::from_sql_interval`].
+///
+/// # Example
+///
+/// ```
+/// #![feature(duration_parse)]
+/// use std::time::Duration;
+///
+/// if let Err(e) = Duration::from_hex_str("ff") {
+///     println!("Failed to parse Duration: {e}");
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[unstable(feature = "duration_parse", issue = "none")]
+pub struct ParseDurationError {
+    kind: ParseDurationErrorKind,
+}
+
+impl ParseDurationError {
+    /// Returns the byte range of the unit that was not recognized in the parsed
+    /// string, or [`None`] if the error has another cause.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #![feature(duration_parse)]
+    /// use std::time::Duration;
+    ///
+    /// let s = "5 minutes 30 secs";
+    /// let span = Duration::parse_human_readable(s).unwrap_err().unknown_unit_span();
+    /// assert_eq!(span, Some(13..17));
+    /// assert_eq!(&s[span.unwrap()], "secs");
+    ///
+    /// assert_eq!(Duration::parse_human_readable("5").unwrap_err().unknown_unit_span(), None);
+    /// ```
+    #[unstable(feature = "duration_parse", issue = "none")]
+    #[must_use]
+    pub fn unknown_unit_span(&self) -> Option<Range<usize>> {
+        match self.kind {
+            ParseDurationErrorKind::UnknownUnit { ref span } => Some(span.start..span.end),
+            _ => None,
+        }
+    }
+
+    /// Returns an `UnknownUnit` error for `unit`, which must be a subslice of
+    /// `input`, the whole string being parsed.
+    fn unknown_unit(input: &str, unit: &str) -> ParseDurationError {
+        let start = unit.as_ptr().addr() - input.as_ptr().addr();
+        let span = start..start + unit.len();
+        ParseDurationError { kind: ParseDurationErrorKind::UnknownUnit { span } }
+    }
+
+    const fn description(&self) -> &'static str {
+        match self.kind {
+            ParseDurationErrorKind::Invalid => "can not parse Duration: invalid syntax",
+            ParseDurationErrorKind::Overflow => "can not parse Duration: value is too big",
+            ParseDurationErrorKind::NanosOutOfRange => {
+                "can not parse Duration: nanoseconds are not less than one second"
+            }
+            ParseDurationErrorKind::UnknownUnit { .. } => "can not parse Duration: unknown unit",
+        }
+    }
+}
+
+#[unstable(feature = "duration_parse", issue = "none")]
+impl fmt::Display for ParseDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.description().fmt(f)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ParseDurationErrorKind {
+    // The string does not follow the expected format.
+    Invalid,
+    // A field of the string is too big for the corresponding field of `Duration`.
+    Overflow,
+    // The nanoseconds are not less than one second.
+    NanosOutOfRange,
+    // A unit of time is not recognized. `span` is its byte range in the input.
+    UnknownUnit { span: Range<usize> },
+}
+
+impl Duration {
+    /// Parses a `Duration` from the hexadecimal format produced by
+    /// `Duration::to_hex_string`.
+    ///
+    /// The string must consist of the whole seconds and the nanoseconds, both in
+    /// hexadecimal and separated by a colon (`SSSSSSSSSSSSSSSS:NNNNNNNN`). Upper- and
+    /// lowercase digits are accepted, and neither part needs to be zero-padded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not in this format, if the seconds do
+    /// not fit in a `u64`, or if the nanoseconds are not less than 1,000,000,000.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_parse)]
+    /// use std::time::Duration;
+    ///
+    /// let res = Duration::from_hex_str("00000000000000ff:3b9ac9ff");
+    /// assert_eq!(res, Ok(Duration::new(255, 999_999_999)));
+    /// let res = Duration::from_hex_str("FF:0");
+    /// assert_eq!(res, Ok(Duration::new(255, 0)));
+    ///
+    /// assert!(Duration::from_hex_str("ff").is_err());
+    /// assert!(Duration::from_hex_str("ff:3b9aca00").is_err());
+    /// assert!(Duration::from_hex_str("10000000000000000:0").is_err());
+    /// ```
+    #[unstable(feature = "duration_parse", issue = "none")]
+    pub fn from_hex_str(s: &str) -> Result<Duration, ParseDurationError> {
+        fn parse_hex(digits: &str) -> Result<u64, ParseDurationError> {
+            if digits.is_empty() {
+                return Err(ParseDurationError { kind: ParseDurationErrorKind::Invalid });
+            }
+            let mut value: u64 = 0;
+            for c in digits.chars() {
+                let digit = match c.to_digit(16) {
+                    Some(digit) => digit,
+                    None => {
+                        return Err(ParseDurationError { kind: ParseDurationErrorKind::Invalid });
+                    }
+                };
+                value = match value.checked_mul(16) {
+                    Some(value) => value + digit as u64,
+                    None => {
+                        return Err(ParseDurationError { kind: ParseDurationErrorKind::Overflow });
+                    }
+                };
+            }
+            Ok(value)
+        }
+
+        let (secs, nanos) = match s.split_once(':') {
+            Some(parts) => parts,
+            None => return Err(ParseDurationError { kind: ParseDurationErrorKind::Invalid }),
+        };
+        let secs = parse_hex(secs)?;
+        let nanos = parse_hex(nanos)?;
+        if nanos > u32::MAX as u64 {
+            Err(ParseDurationError { kind: ParseDurationErrorKind::Overflow })
+        } else if nanos >= NANOS_PER_SEC as u64 {
+            Err(ParseDurationError { kind: ParseDurationErrorKind::NanosOutOfRange })
+        } else {
+            Ok(Duration::new(secs, nanos as u32))
+        }
+    }
+    /// Parses a `Duration` from a human-readable list of amounts of time, such as
+    /// `"5 minutes 30 seconds"`.
+    ///
+    /// The string must consist of whitespace-separated pairs of a number and a
+    /// unit. The numbers are non-negative decimal numbers and may have a
+    /// fractional part (`"1.5 hours"`), the units are `nanosecond`,
+    /// `microsecond`, `millisecond`, `second`, `minute`, `hour`, `day` and
+    /// `week`, either in singular or in plural. The pairs may be given in any
+    /// order, and units that appear more than once are added together. The
+    /// result is truncated to whole nanoseconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is empty or not in this format, if it
+    /// contains an unknown unit, or if the sum overflows `Duration`.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_parse)]
+    /// use std::time::Duration;
+    ///
+    /// let res = Duration::parse_human_readable("5 minutes 30 seconds");
+    /// assert_eq!(res, Ok(Duration::from_secs(330)));
+    /// let res = Duration::parse_human_readable("1 second 500 milliseconds");
+    /// assert_eq!(res, Ok(Duration::from_millis(1_500)));
+    /// let res = Duration::parse_human_readable("1.5 hours");
+    /// assert_eq!(res, Ok(Duration::from_secs(5_400)));
+    /// let res = Duration::parse_human_readable("1 week 1 day 1 day");
+    /// assert_eq!(res, Ok(Duration::from_secs(9 * 24 * 60 * 60)));
+    ///
+    /// assert!(Duration::parse_human_readable("").is_err());
+    /// assert!(Duration::parse_human_readable("5").is_err());
+    /// assert!(Duration::parse_human_readable("5 fortnights").is_err());
+    /// assert!(Duration::parse_human_readable("-5 seconds").is_err());
+    /// ```
+    #[unstable(feature = "duration_parse", issue = "none")]
+    pub fn parse_human_readable(s: &str) -> Result<Duration, ParseDurationError> {
+        let mut words = s.split_whitespace();
+        let mut total_nanos: u128 = 0;
+        let mut empty = true;
+        while let Some(number) = words.next() {
+            let unit_nanos = match words.next() {
+                Some("nanosecond" | "nanoseconds") => 1,
+                Some("microsecond" | "microseconds") => NANOS_PER_MICRO as u64,
+                Some("millisecond" | "milliseconds") => NANOS_PER_MILLI as u64,
+                Some("second" | "seconds") => NANOS_PER_SEC as u64,
+                Some("minute" | "minutes") => NANOS_PER_MINUTE,
+                Some("hour" | "hours") => NANOS_PER_HOUR,
+                Some("day" | "days") => NANOS_PER_DAY,
+                Some("week" | "weeks") => NANOS_PER_WEEK,
+                Some(unit) => return Err(ParseDurationError::unknown_unit(s, unit)),
+                None => return Err(ParseDurationError { kind: ParseDurationErrorKind::Invalid }),
+            };
+            let nanos = parse_decimal(number, unit_nanos)?;
+            total_nanos = match total_nanos.checked_add(nanos) {
+                Some(total_nanos) => total_nanos,
+                None => return Err(ParseDurationError { kind: ParseDurationErrorKind::Overflow }),
+            };
+            empty = false;
+        }
+        if empty {
+            return Err(ParseDurationError { kind: ParseDurationErrorKind::Invalid });
+        }
+        duration_from_parsed_nanos(total_nanos)
+    }
+
+    /// Parses a `Duration` from any of several common formats.
+    ///
+    /// This is meant for permissive parsers of user input, such as those of
+    /// configuration files. Leading and trailing whitespace is ignored, and the
+    /// following formats are tried in order, from the most specific to the least
+    /// specific one:
+    ///
+    /// 1. An ISO 8601 duration such as `"PT5S"` or `"P1DT2H30M"`. Only weeks
+    ///    (`W`) and days (`D`) are supported before the `T`, and hours (`H`),
+    ///    minutes (`M`) and seconds (`S`) after it, each at most once and in this
+    ///    order. Years and months are not supported because their length varies.
+    /// 2. A human-readable list of amounts of time such as `"5 minutes 30 seconds"`,
+    ///    as accepted by [`Duration::parse_human_readable`].
+    /// 3. A list of numbers with a unit suffix such as `"5s"` or `"1h30m"`. The
+    ///    suffixes are `ns`, `us` (or `µs`), `ms`, `s`, `m`, `h`, `d` and `w`.
+    /// 4. A plain number of seconds such as `"5"` or `"5.0"`.
+    ///
+    /// The result of the first format that matches is returned. In all formats
+    /// the numbers are non-negative decimal numbers that may have a fractional
+    /// part, and the result is truncated to whole nanoseconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is in none of these formats. If any of the
+    /// formats came close to matching, for example if the string contains an
+    /// unknown unit or the value overflows `Duration`, that error is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_parse)]
+    /// use std::time::Duration;
+    ///
+    /// let five_secs = Ok(Duration::from_secs(5));
+    /// assert_eq!(Duration::from_str_flexible("PT5S"), five_secs);
+    /// assert_eq!(Duration::from_str_flexible("5 seconds"), five_secs);
+    /// assert_eq!(Duration::from_str_flexible("5s"), five_secs);
+    /// assert_eq!(Duration::from_str_flexible("5.0"), five_secs);
+    ///
+    /// let res = Duration::from_str_flexible("1h30m");
+    /// assert_eq!(res, Ok(Duration::from_secs(5_400)));
+    ///
+    /// assert!(Duration::from_str_flexible("").is_err());
+    /// assert!(Duration::from_str_flexible("five seconds").is_err());
+    /// assert!(Duration::from_str_flexible("P1Y").is_err());
+    /// assert!(Duration::from_str_flexible("-5s").is_err());
+    /// ```
+    #[unstable(feature = "duration_parse", issue = "none")]
+    pub fn from_str_flexible(s: &str) -> Result<Duration, ParseDurationError> {
+        let input = s;
+        let s = s.trim();
+        let parsers: [fn(&str) -> Result<Duration, ParseDurationError>; 4] =
+            [parse_iso8601, Duration::parse_human_readable, parse_suffixed, parse_secs];
+        let mut error = ParseDurationError { kind: ParseDurationErrorKind::Invalid };
+        for parse in parsers {
+            match parse(s) {
+                Ok(duration) => return Ok(duration),
+                // Keep the first error that says more than "this is not the format".
+                Err(e) if error.kind == ParseDurationErrorKind::Invalid => error = e,
+                Err(_) => {}
+            }
+        }
+        // The parsers only see the trimmed string, so move the span of an
+        // unknown unit back to its position in `input`.
+        if let ParseDurationErrorKind::UnknownUnit { ref mut span } = error.kind {
+            let offset = s.as_ptr().addr() - input.as_ptr().addr();
+            *span = span.start + offset..span.end + offset;
+        }
+        Err(error)
+    }
+
+    /// Parses a `Duration` from the text representation of a PostgreSQL
+    /// `INTERVAL`.
+    ///
+    /// Leading and trailing whitespace is ignored, and all of the formats that
+    /// PostgreSQL outputs depending on its `IntervalStyle` setting, other than
+    /// `sql_standard`, are accepted:
+    ///
+    /// * `postgres`, the default, such as `"3 days 04:05:06.5"` or `"00:00:05"`:
+    ///   amounts of the units `year`, `mon`, `day`, `hour`, `min` and `sec`,
+    ///   singular or plural, optionally followed by a time of the form
+    ///   `HH:MM:SS` with optional fractional seconds, which may appear on its
+    ///   own.
+    /// * `postgres_verbose`, such as `"@ 3 days 4 hours 5 mins 6.5 secs"`: the
+    ///   same, with a leading `@`.
+    /// * `iso_8601`, such as `"P3DT4H5M6.5S"` or `"PT5S"`: an ISO 8601 duration,
+    ///   which may also have years (`Y`) and months (`M`) before the `T`.
+    ///
+    /// Since the length of a year or a month varies, their amounts must be zero,
+    /// as in `"0 years 0 mons 0 days 0 hours 0 mins 5.000000 secs"`. The result
+    /// is truncated to whole nanoseconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not in one of these formats, if it has
+    /// a non-zero amount of years or months, if it is negative, or if the sum
+    /// overflows `Duration`.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(duration_parse)]
+    /// use std::time::Duration;
+    ///
+    /// let five_secs = Ok(Duration::from_secs(5));
+    /// assert_eq!(Duration::from_sql_interval("00:00:05"), five_secs);
+    /// assert_eq!(Duration::from_sql_interval("PT5S"), five_secs);
+    /// let verbose = "0 years 0 mons 0 days 0 hours 0 mins 5.000000 secs";
+    /// assert_eq!(Duration::from_sql_interval(verbose), five_secs);
+    ///
+    /// let res = Duration::from_sql_interval("1 day 02:30:00.25");
+    /// assert_eq!(res, Ok(Duration::new(95_400, 250_000_000)));
+    ///
+    /// assert!(Duration::from_sql_interval("1 mon").is_err());
+    /// assert!(Duration::from_sql_interval("-00:00:05").is_err());
+    /// assert!(Duration::from_sql_interval("@ 5 secs ago").is_err());
+    /// ```
+    #[unstable(feature = "duration_parse", issue = "none")]
+    pub fn from_sql_interval(s: &str) -> Result<Duration, ParseDurationError> {
+        const DATE_UNITS: [(&str, u64); 4] = [
+            ("Y", VARIABLE_LENGTH_UNIT),
+            ("M", VARIABLE_LENGTH_UNIT),
+            ("W", NANOS_PER_WEEK),
+            ("D", NANOS_PER_DAY),
+        ];
+
+        let input = s;
+        let s = s.trim();
+        if s.starts_with('P') {
+            return parse_iso8601_with_date_units(input, s, &DATE_UNITS);
+        }
+
+        let mut words = s.split_whitespace().peekable();
+        // The `postgres_verbose` format starts with an `@`.
+        words.next_if_eq(&"@");
+        let mut total_nanos: u128 = 0;
+        let mut empty = true;
+        while let Some(number) = words.next() {
+            let nanos = if number.contains(':') {
+                // The `HH:MM:SS` time, if any, comes last.
+                if words.peek().is_some() {
+                    return Err(ParseDurationError { kind: ParseDurationErrorKind::Invalid });
+                }
+                parse_clock_time(number)?
+            } else {
+                let unit_nanos = match words.next() {
+                    Some("year" | "years" | "mon" | "mons") => VARIABLE_LENGTH_UNIT,
+                    Some("day" | "days") => NANOS_PER_DAY,
+                    Some("hour" | "hours") => NANOS_PER_HOUR,
+                    Some("min" | "mins") => NANOS_PER_MINUTE,
+                    Some("sec" | "secs") => NANOS_PER_SEC as u64,
+                    Some(unit) => return Err(ParseDurationError::unknown_unit(input, unit)),
+                    None => {
+                        return Err(ParseDurationError { kind: ParseDurationErrorKind::Invalid });
+                    }
+                };
+                parse_amount(number, unit_nanos)?
+            };
+            total_nanos = match total_nanos.checked_add(nanos) {
+                Some(total_nanos) => total_nanos,
+                None => return Err(ParseDurationError { kind: ParseDurationErrorKind::Overflow }),
+            };
+            empty = false;
+        }
+        if empty {
+            return Err(ParseDurationError { kind: ParseDurationErrorKind::Invalid });
+        }
+        duration_from_parsed_nanos(total_nanos)
+    }
+}
+
+/// Computes the greatest common divisor of `a` and `b` with the Euclidean
+/// algorithm.
+const fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Returns the median of `sorted`, which must be non-empty and sorted in
+/// ascending order. For an even number of samples, this is the midpoint of the
+/// two middle ones, rounded down to whole nanoseconds.
+fn sorted_median(sorted: &[Duration]) -> Duration {
+    let mid = sorted.len() / 2;
+    if sorted.len() % 2 == 1 {
+        sorted[mid]
+    } else {
+        let (low, high) = (sorted[mid - 1], sorted[mid]);
+        // `low <= high`, so this neither underflows nor overflows.
+        low + (high - low) / 2
+    }
+}
+
+/// Decodes a two-digit binary-coded decimal number, returning [`None`] if
+/// either digit is greater than 9.
+const fn bcd_to_binary(bcd: u8) -> Option<u8> {
+    let (tens, units) = (bcd >> 4, bcd & 0x0f);
+    if tens > 9 || units > 9 { None } else { Some(tens * 10 + units) }
+}
+
+/// Parses a non-negative decimal number of some unit of time into a number of
+/// nanoseconds.
+///
+/// The number consists of an integer part and an optional fractional part
+/// separated by a `.`, and at least one of them must not be empty. The number of
+/// nanoseconds in the unit is given by `unit_nanos`, and the result is truncated
+/// to whole nanoseconds.
+fn parse_decimal(number: &str, unit_nanos: u64) -> Result<u128, ParseDurationError> {
+    // No unit has more than 10^15 nanoseconds, so any digit after the 18th one
+    // contributes less than a nanosecond and can be ignored. This also keeps the
+    // fractional part small enough to multiply it by the unit without overflow.
+    const MAX_FRACTIONAL_DIGITS: usize = 18;
+
+    let (integer_part, fractional_part) = number.split_once('.').unwrap_or((number, ""));
+    if integer_part.is_empty() && fractional_part.is_empty() {
+        return Err(ParseDurationError { kind: ParseDurationErrorKind::Invalid });
+    }
+
+    let mut integer: u128 = 0;
+    for b in integer_part.bytes() {
+        if !b.is_ascii_digit() {
+            return Err(ParseDurationError { kind: ParseDurationErrorKind::Invalid });
+        }
+        integer = match integer.checked_mul(10).and_then(|v| v.checked_add((b - b'0') as u128)) {
+            Some(integer) => integer,
+            None => return Err(ParseDurationError { kind: ParseDurationErrorKind::Overflow }),
+        };
+    }
+
+    let mut fraction: u128 = 0;
+    let mut divisor: u128 = 1;
+    for (i, b) in fractional_part.bytes().enumerate() {
+        if !b.is_ascii_digit() {
+            return Err(ParseDurationError { kind: ParseDurationErrorKind::Invalid });
+        }
+        if i < MAX_FRACTIONAL_DIGITS {
+            fraction = fraction * 10 + (b - b'0') as u128;
+            divisor *= 10;
+        }
+    }
+
+    match integer.checked_mul(unit_nanos as u128) {
+        Some(nanos) => match nanos.checked_add(fraction * unit_nanos as u128 / divisor) {
+            Some(nanos) => Ok(nanos),
+            None => Err(ParseDurationError { kind: ParseDurationErrorKind::Overflow }),
+        },
+        None => Err(ParseDurationError { kind: ParseDurationErrorKind::Overflow }),
+    }
+}
+
+/// The number of nanoseconds given for a unit of time whose length varies, such
+/// as a month, to [`parse_amount`] and [`parse_components`].
+const VARIABLE_LENGTH_UNIT: u64 = 0;
+
+/// Parses a non-negative decimal number of some unit of time into a number of
+/// nanoseconds, like [`parse_decimal`].
+///
+/// If `unit_nanos` is [`VARIABLE_LENGTH_UNIT`], the number has no fixed length
+/// in nanoseconds and must therefore be zero.
+fn parse_amount(number: &str, unit_nanos: u64) -> Result<u128, ParseDurationError> {
+    if unit_nanos != VARIABLE_LENGTH_UNIT {
+        return parse_decimal(number, unit_nanos);
+    }
+    // This checks the format of the number.
+    parse_decimal(number, 1)?;
+    if number.bytes().all(|b| b == b'0' || b == b'.') {
+        Ok(0)
+    } else {
+        Err(ParseDurationError { kind: ParseDurationErrorKind::Invalid })
+    }
+}
+
+/// Parses a sequence of numbers, each immediately followed by a unit, into a
+/// number of nanoseconds, such as `"1h30m"` with the units `h` and `m`.
+///
+/// `units` lists the accepted units together with their number of nanoseconds,
+/// which may be [`VARIABLE_LENGTH_UNIT`].
+/// If `ordered` is true, the units must appear in the order of `units` and at
+/// most once each, otherwise they may appear in any order and are added
+/// together. `s` must be a subslice of `input`, the whole string being parsed,
+/// which unknown units are reported relative to.
+fn parse_components(
+    input: &str,
+    s: &str,
+    units: &[(&str, u64)],
+    ordered: bool,
+) -> Result<u128, ParseDurationError> {
+    let is_number = |c: char| c.is_ascii_digit() || c == '.';
+    if s.is_empty() {
+        return Err(ParseDurationError { kind: ParseDurationErrorKind::Invalid });
+    }
+
+    let mut rest = s;
+    let mut next_unit = 0;
+    let mut total_nanos: u128 = 0;
+    while !rest.is_empty() {
+        let (number, tail) = rest.split_at(rest.find(|c| !is_number(c)).unwrap_or(rest.len()));
+        let (unit, tail) = tail.split_at(tail.find(is_number).unwrap_or(tail.len()));
+        if number.is_empty() || unit.is_empty() {
+            return Err(ParseDurationError { kind: ParseDurationErrorKind::Invalid });
+        }
+        let index = match units.iter().position(|&(name, _)| name == unit) {
+            Some(index) => index,
+            None => return Err(ParseDurationError::unknown_unit(input, unit)),
+        };
+        if ordered {
+            if index < next_unit {
+                return Err(ParseDurationError { kind: ParseDurationErrorKind::Invalid });
+            }
+            next_unit = index + 1;
+        }
+        let nanos = parse_amount(number, units[index].1)?;
+        total_nanos = match total_nanos.checked_add(nanos) {
+            Some(total_nanos) => total_nanos,
+            None => return Err(ParseDurationError { kind: ParseDurationErrorKind::Overflow }),
+        };
+        rest = tail;
+    }
+    Ok(total_nanos)
+}
+
+/// Converts a number of nanoseconds parsed from a string into a `Duration`.
+fn duration_from_parsed_nanos(nanos: u128) -> Result<Duration, ParseDurationError> {
+    match Duration::from_nanos_u128(nanos) {
+        Some(duration) => Ok(duration),
+        None => Err(ParseDurationError { kind: ParseDurationErrorKind::Overflow }),
+    }
+}
+
+/// Parses an ISO 8601 duration such as `"P1DT2H30M"`, without years and months.
+fn parse_iso8601(s: &str) -> Result<Duration, ParseDurationError> {
+    const DATE_UNITS: [(&str, u64); 2] = [("W", NANOS_PER_WEEK), ("D", NANOS_PER_DAY)];
+
+    parse_iso8601_with_date_units(s, s, &DATE_UNITS)
+}
+
+/// Parses an ISO 8601 duration such as `"P1DT2H30M"`, with the units in
+/// `date_units` before the `T`, as [`parse_components`] accepts them. `s` must
+/// be a subslice of `input`, the whole string being parsed.
+fn parse_iso8601_with_date_units(
+    input: &str,
+    s: &str,
+    date_units: &[(&str, u64)],
+) -> Result<Duration, ParseDurationError> {
+    const TIME_UNITS: [(&str, u64); 3] =
+        [("H", NANOS_PER_HOUR), ("M", NANOS_PER_MINUTE), ("S", NANOS_PER_SEC as u64)];
+
+    let s = match s.strip_prefix('P') {
+        Some(s) => s,
+        None => return Err(ParseDurationError { kind: ParseDurationErrorKind::Invalid }),
+    };
+    let (date, time) = match s.split_once('T') {
+        Some((date, time)) => (date, parse_components(input, time, &TIME_UNITS, true)?),
+        None => (s, 0),
+    };
+    let date = match date {
+        // The date part may only be left out if there is a time part.
+        "" if s.len() > date.len() => 0,
+        date => parse_components(input, date, date_units, true)?,
+    };
+    match date.checked_add(time) {
+        Some(nanos) => duration_from_parsed_nanos(nanos),
+        None => Err(ParseDurationError { kind: ParseDurationErrorKind::Overflow }),
+    }
+}
+
+/// Parses a sequence of numbers with a unit suffix such as `"1h30m"`.
+fn parse_suffixed(s: &str) -> Result<Duration, ParseDurationError> {
+    const UNITS: [(&str, u64); 9] = [
+        ("ns", 1),
+        ("us", NANOS_PER_MICRO as u64),
+        ("µs", NANOS_PER_MICRO as u64),
+        ("ms", NANOS_PER_MILLI as u64),
+        ("s", NANOS_PER_SEC as u64),
+        ("m", NANOS_PER_MINUTE),
+        ("h", NANOS_PER_HOUR),
+        ("d", NANOS_PER_DAY),
+        ("w", NANOS_PER_WEEK),
+    ];
+
+    duration_from_parsed_nanos(parse_components(s, s, &UNITS, false)?)
+}
+
+/// Parses a plain decimal number of seconds such as `"5.0"`.
+fn parse_secs(s: &str) -> Result<Duration, ParseDurationError> {
+    duration_from_parsed_nanos(parse_decimal(s, NANOS_PER_SEC as u64)?)
+}
+
+/// Parses a time of the form `HH:MM:SS` into a number of nanoseconds, such as
+/// `"04:05:06.5"`.
+///
+/// The hours may have any number of digits, while the minutes and seconds have
+/// two digits each and are less than 60. Only the seconds may have a fractional
+/// part.
+fn parse_clock_time(s: &str) -> Result<u128, ParseDurationError> {
+    let mut fields = s.split(':');
+    let (hours, minutes, seconds) =
+        match (fields.next(), fields.next(), fields.next(), fields.next()) {
+            (Some(hours), Some(minutes), Some(seconds), None) => (hours, minutes, seconds),
+            _ => return Err(ParseDurationError { kind: ParseDurationErrorKind::Invalid }),
+        };
+    let whole_seconds = seconds.split_once('.').map_or(seconds, |(whole, _)| whole);
+    if hours.contains('.')
+        || minutes.len() != 2
+        || minutes.contains('.')
+        || whole_seconds.len() != 2
+    {
+        return Err(ParseDurationError { kind: ParseDurationErrorKind::Invalid });
+    }
+
+    let hours = parse_decimal(hours, NANOS_PER_HOUR)?;
+    let minutes = parse_decimal(minutes, NANOS_PER_MINUTE)?;
+    let seconds = parse_decimal(seconds, NANOS_PER_SEC as u64)?;
+    if minutes >= NANOS_PER_HOUR as u128 || seconds >= NANOS_PER_MINUTE as u128 {
+        return Err(ParseDurationError { kind: ParseDurationErrorKind::Invalid });
+    }
+    match hours.checked_add(minutes + seconds) {
+        Some(nanos) => Ok(nanos),
+        None => Err(ParseDurationError { kind: ParseDurationErrorKind::Overflow }),
+    }
 }
@@ -31,8 +31,58 @@
 #![feature(core_private_diy_float)]
 #![feature(dec2flt)]
 #![feature(div_duration)]
-#![feature(duration_consts_float)]
+#![feature(duration_abs_diff)]
+#![feature(duration_add_units_checked)]
+#![feature(duration_approx)]
+#![feature(duration_as_secs_u128)]
+#![feature(duration_bytes)]
+#![feature(duration_cdda)]
+#![feature(duration_checked_add_micros)]
+#![feature(duration_checked_mul_add)]
+#![feature(duration_checked_sum_iter)]
+#![feature(duration_clamp_to_range)]
+#![feature(duration_const_cmp)]
 #![feature(duration_constants)]
+#![feature(duration_constructors)]
+#![feature(duration_consts_float)]
+#![feature(duration_double_half)]
+#![feature(duration_float_ops)]
+#![feature(duration_float_units)]
+#![feature(duration_from_bcd_time)]
+#![feature(duration_from_nanos_f64)]
+#![feature(duration_from_secs_rational)]
+#![feature(duration_from_u128)]
+#![feature(duration_gcd)]
+#![feature(duration_go)]
+#![feature(duration_is_multiple_of)]
+#![feature(duration_javascript)]
+#![feature(duration_lcm)]
+#![feature(duration_mul_u64)]
+#![feature(duration_new_checked)]
+#![feature(duration_new_saturating)]
+#![feature(duration_parse)]
+#![feature(duration_pct)]
+#![feature(duration_protobuf)]
+#![feature(duration_python)]
+#![feature(duration_range)]
+#![feature(duration_ratio)]
+#![feature(duration_reciprocal)]
+#![feature(duration_rounding)]
+#![feature(duration_saturating_add_secs)]
+#![feature(duration_scale_to_fit)]
+#![feature(duration_secs_i64)]
+#![feature(duration_since_epoch)]
+#![feature(duration_smpte_timecode)]
+#![feature(duration_split_at)]
+#![feature(duration_spread)]
+#![feature(duration_stats)]
+#![feature(duration_step_iter)]
+#![feature(duration_sub_or_negative)]
+#![feature(duration_timeout)]
+#![feature(duration_total_nanos_checked)]
+#![feature(duration_try_sub)]
+#![feature(duration_wrapping)]
+#![feature(signed_duration)]
 #![feature(exact_size_is_empty)]
 #![feature(extern_types)]
 #![feature(flt2dec)]
@@ -1,4 +1,4 @@
-use core::time::Duration;
+use core::time::{Duration, DurationRange, SignedDuration};
 
 #[test]
 fn creation() {
@@ -9,6 +9,7 @@ fn creation() {
         Duration::new(4, 10 * 1_000_000)
     );
     assert_eq!(Duration::from_millis(4000), Duration::new(4, 0));
+    assert_eq!(Duration::default(), Duration::ZERO);
 }
 
 #[test]
@@ -17,6 +18,28 @@ fn new_overflow() {
     let _ = Duration::new(u64::MAX, 1_000_000_000);
 }
 
+#[test]
+fn new_checked() {
+    assert_eq!(Duration::new_checked(0, 0), Some(Duration::ZERO));
+    assert_eq!(Duration::new_checked(1, 999_999_999), Some(Duration::new(1, 999_999_999)));
+    assert_eq!(Duration::new_checked(1, 1_000_000_000), Some(Duration::new(2, 0)));
+    assert_eq!(Duration::new_checked(0, u32::MAX), Some(Duration::new(4, 294_967_295)));
+    assert_eq!(Duration::new_checked(u64::MAX, 999_999_999), Some(Duration::MAX));
+    assert_eq!(Duration::new_checked(u64::MAX, 1_000_000_000), None);
+    assert_eq!(Duration::new_checked(u64::MAX - 3, u32::MAX), None);
+}
+
+#[test]
+fn new_saturating() {
+    assert_eq!(Duration::new_saturating(0, 0), Duration::ZERO);
+    assert_eq!(Duration::new_saturating(1, 1_000_000_000), Duration::new(2, 0));
+    assert_eq!(Duration::new_saturating(u64::MAX, 999_999_999), Duration::MAX);
+    assert_eq!(Duration::new_saturating(u64::MAX, 1_000_000_000), Duration::MAX);
+    assert_eq!(Duration::new_saturating(u64::MAX, u32::MAX), Duration::MAX);
+    const D: Duration = Duration::new_saturating(u64::MAX - 3, u32::MAX);
+    assert_eq!(D, Duration::MAX);
+}
+
 #[test]
 fn secs() {
     assert_eq!(Duration::new(0, 0).as_secs(), 0);
@@ -73,6 +96,161 @@ fn nanos() {
     assert_eq!(Duration::from_nanos(1_000_000_001).subsec_nanos(), 1);
 }
 
+#[test]
+fn from_units() {
+    assert_eq!(Duration::from_mins(0), Duration::ZERO);
+    assert_eq!(Duration::from_mins(2), Duration::from_secs(120));
+    assert_eq!(Duration::from_hours(3), Duration::from_secs(3 * 3_600));
+    assert_eq!(Duration::from_days(4), Duration::from_secs(4 * 86_400));
+    assert_eq!(Duration::from_weeks(5), Duration::from_secs(5 * 604_800));
+    assert_eq!(Duration::from_mins(u64::MAX / 60), Duration::from_secs(u64::MAX / 60 * 60));
+    assert_eq!(Duration::from_weeks(u64::MAX / 604_800).as_secs(), u64::MAX / 604_800 * 604_800);
+
+    const TIMEOUT: Duration = Duration::from_hours(1);
+    assert_eq!(TIMEOUT, Duration::from_mins(60));
+}
+
+#[test]
+#[should_panic(expected = "overflow in Duration::from_mins")]
+fn from_mins_overflow() {
+    let _ = Duration::from_mins(u64::MAX / 60 + 1);
+}
+
+#[test]
+#[should_panic(expected = "overflow in Duration::from_weeks")]
+fn from_weeks_overflow() {
+    let _ = Duration::from_weeks(u64::MAX / 604_800 + 1);
+}
+
+#[test]
+fn from_u128() {
+    assert_eq!(Duration::from_nanos_u128(0), Some(Duration::ZERO));
+    assert_eq!(Duration::from_nanos_u128(1_000_000_123), Some(Duration::new(1, 123)));
+    assert_eq!(Duration::from_nanos_u128(Duration::MAX.as_nanos()), Some(Duration::MAX));
+    assert_eq!(Duration::from_nanos_u128(Duration::MAX.as_nanos() + 1), None);
+    assert_eq!(Duration::from_nanos_u128(u128::MAX), None);
+
+    assert_eq!(Duration::from_micros_u128(0), Some(Duration::ZERO));
+    assert_eq!(Duration::from_micros_u128(1_000_002), Some(Duration::new(1, 2_000)));
+    let max_micros = Duration::MAX.as_micros();
+    assert_eq!(Duration::from_micros_u128(max_micros), Some(Duration::new(u64::MAX, 999_999_000)));
+    assert_eq!(Duration::from_micros_u128(max_micros + 1), None);
+    assert_eq!(Duration::from_micros_u128(u128::MAX / 1_000 + 1), None);
+}
+
+#[test]
+fn since_epoch() {
+    assert_eq!(Duration::since_epoch(0, 0), Duration::ZERO);
+    assert_eq!(Duration::since_epoch(1, 1_000_000_002), Duration::new(1, 1));
+    assert_eq!(Duration::since_epoch(1_000_000_002, 1), Duration::ZERO);
+    assert_eq!(Duration::since_epoch(u128::MAX, 0), Duration::ZERO);
+    assert_eq!(Duration::since_epoch(0, Duration::MAX.as_nanos()), Duration::MAX);
+    assert_eq!(Duration::since_epoch(0, u128::MAX), Duration::MAX);
+    assert_eq!(Duration::since_epoch(u128::MAX - 5, u128::MAX), Duration::from_nanos(5));
+}
+
+#[test]
+fn from_secs_rational() {
+    assert_eq!(Duration::from_secs_rational(0, 1), Some(Duration::ZERO));
+    assert_eq!(Duration::from_secs_rational(2, 3), Some(Duration::new(0, 666_666_666)));
+    assert_eq!(Duration::from_secs_rational(7, 2), Some(Duration::new(3, 500_000_000)));
+    assert_eq!(Duration::from_secs_rational(u64::MAX, 1), Some(Duration::from_secs(u64::MAX)));
+    let almost_one = Duration::from_secs_rational(u64::MAX - 1, u64::MAX);
+    assert_eq!(almost_one, Some(Duration::new(0, 999_999_999)));
+    assert_eq!(Duration::from_secs_rational(1, u64::MAX), Some(Duration::ZERO));
+    assert_eq!(Duration::from_secs_rational(0, 0), None);
+}
+
+#[test]
+fn from_bcd_time() {
+    assert_eq!(Duration::from_bcd_time(0x00, 0x00, 0x00, 0x00, 30), Some(Duration::ZERO));
+    assert_eq!(
+        Duration::from_bcd_time(0x23, 0x59, 0x59, 0x29, 30),
+        Some(Duration::new(86_399, 966_666_666))
+    );
+    assert_eq!(
+        Duration::from_bcd_time(0x10, 0x00, 0x00, 0x00, 24),
+        Some(Duration::from_secs(36_000))
+    );
+    assert_eq!(
+        Duration::from_bcd_time(0x00, 0x00, 0x01, 0x01, 1_000),
+        Some(Duration::new(1, 1_000_000))
+    );
+
+    assert_eq!(Duration::from_bcd_time(0x24, 0x00, 0x00, 0x00, 30), None);
+    assert_eq!(Duration::from_bcd_time(0x00, 0x60, 0x00, 0x00, 30), None);
+    assert_eq!(Duration::from_bcd_time(0x00, 0x00, 0x60, 0x00, 30), None);
+    assert_eq!(Duration::from_bcd_time(0x00, 0x00, 0x00, 0x30, 30), None);
+    assert_eq!(Duration::from_bcd_time(0x00, 0x00, 0x00, 0x00, 0), None);
+    assert_eq!(Duration::from_bcd_time(0x0a, 0x00, 0x00, 0x00, 30), None);
+    assert_eq!(Duration::from_bcd_time(0x00, 0x00, 0x00, 0xa0, 30), None);
+    assert_eq!(Duration::from_bcd_time(0xff, 0xff, 0xff, 0xff, u32::MAX), None);
+}
+
+#[test]
+fn as_smpte_timecode() {
+    assert_eq!(Duration::ZERO.as_smpte_timecode(30), (0, 0, 0, 0));
+    assert_eq!(Duration::new(86_399, 999_999_999).as_smpte_timecode(30), (23, 59, 59, 29));
+    assert_eq!(Duration::from_secs(86_400).as_smpte_timecode(30), (0, 0, 0, 0));
+    assert_eq!(Duration::new(1, 999_999_999).as_smpte_timecode(256), (0, 0, 1, 255));
+    assert_eq!(Duration::MAX.as_smpte_timecode(1), (7, 0, 15, 0));
+
+    let d = Duration::from_bcd_time(0x12, 0x34, 0x56, 0x23, 24).unwrap();
+    assert_eq!(d.as_smpte_timecode(24), (12, 34, 56, 23));
+    assert_eq!((d - Duration::NANOSECOND).as_smpte_timecode(24), (12, 34, 56, 22));
+    for fps in [1, 24, 25, 30, 60, 99] {
+        for frame in 0..fps as u8 {
+            let bcd = frame / 10 * 16 + frame % 10;
+            let d = Duration::from_bcd_time(0x00, 0x00, 0x00, bcd, fps).unwrap();
+            assert_eq!(d.as_smpte_timecode(fps), (0, 0, 0, frame));
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn as_smpte_timecode_zero_fps() {
+    let _ = Duration::SECOND.as_smpte_timecode(0);
+}
+
+#[test]
+#[should_panic]
+fn as_smpte_timecode_large_fps() {
+    let _ = Duration::SECOND.as_smpte_timecode(257);
+}
+
+#[test]
+fn cdda_frames() {
+    assert_eq!(Duration::from_cdda_frames(0), Duration::ZERO);
+    assert_eq!(Duration::from_cdda_frames(74), Duration::new(0, 986_666_666));
+    assert_eq!(Duration::from_cdda_frames(75), Duration::SECOND);
+    assert_eq!(Duration::from_cdda_frames(u64::MAX), Duration::new(u64::MAX / 75, 200_000_000));
+
+    assert_eq!(Duration::ZERO.to_cdda_frames(), 0);
+    assert_eq!(Duration::from_nanos(13_333_332).to_cdda_frames(), 0);
+    assert_eq!(Duration::from_nanos(13_333_333).to_cdda_frames(), 1);
+    assert_eq!(Duration::new(0, 999_999_999).to_cdda_frames(), 74);
+    for frames in (0..300).chain(u64::MAX - 300..=u64::MAX) {
+        assert_eq!(Duration::from_cdda_frames(frames).to_cdda_frames(), frames);
+    }
+}
+
+#[test]
+#[should_panic]
+fn to_cdda_frames_overflow() {
+    let _ = Duration::MAX.to_cdda_frames();
+}
+
+#[test]
+fn from_millis_u128() {
+    assert_eq!(Duration::from_millis_u128(0), Some(Duration::ZERO));
+    assert_eq!(Duration::from_millis_u128(1_002), Some(Duration::new(1, 2_000_000)));
+    let max_millis = Duration::MAX.as_millis();
+    assert_eq!(Duration::from_millis_u128(max_millis), Some(Duration::new(u64::MAX, 999_000_000)));
+    assert_eq!(Duration::from_millis_u128(max_millis + 1), None);
+    assert_eq!(Duration::from_millis_u128(u128::MAX), None);
+}
+
 #[test]
 fn add() {
     assert_eq!(Duration::new(0, 0) + Duration::new(0, 1), Duration::new(0, 1));
@@ -89,6 +267,46 @@ fn checked_add() {
     assert_eq!(Duration::new(1, 0).checked_add(Duration::new(u64::MAX, 0)), None);
 }
 
+#[test]
+fn checked_add_micros() {
+    assert_eq!(Duration::ZERO.checked_add_micros(0), Some(Duration::ZERO));
+    assert_eq!(Duration::new(0, 1).checked_add_micros(1_000_001), Some(Duration::new(1, 1_001)));
+    assert_eq!(Duration::new(0, 999_999_999).checked_add_micros(1), Some(Duration::new(1, 999)));
+    let max_micros = u64::MAX as u128 * 1_000_000 + 999_999;
+    assert_eq!(
+        Duration::ZERO.checked_add_micros(max_micros),
+        Some(Duration::new(u64::MAX, 999_999_000))
+    );
+    assert_eq!(Duration::new(0, 1_000).checked_add_micros(max_micros), None);
+    assert_eq!(Duration::new(0, 999).checked_add_micros(max_micros), Some(Duration::MAX));
+    assert_eq!(Duration::new(1, 0).checked_add_micros(u64::MAX as u128 * 1_000_000), None);
+    assert_eq!(Duration::ZERO.checked_add_micros(max_micros + 1), None);
+    assert_eq!(Duration::ZERO.checked_add_micros(u128::MAX), None);
+}
+
+#[test]
+fn add_units_checked() {
+    let d = Duration::new(10, 5);
+    assert_eq!(d.add_minutes_checked(0), Some(d));
+    assert_eq!(d.add_minutes_checked(3), Some(Duration::new(190, 5)));
+    assert_eq!(d.add_hours_checked(1), Some(Duration::new(3_610, 5)));
+    assert_eq!(d.add_days_checked(1), Some(Duration::new(86_410, 5)));
+
+    assert_eq!(
+        Duration::ZERO.add_minutes_checked(u64::MAX / 60),
+        Some(Duration::from_secs(u64::MAX / 60 * 60))
+    );
+    assert_eq!(Duration::ZERO.add_minutes_checked(u64::MAX / 60 + 1), None);
+    assert_eq!(Duration::ZERO.add_hours_checked(u64::MAX / 3_600 + 1), None);
+    assert_eq!(Duration::ZERO.add_days_checked(u64::MAX / 86_400 + 1), None);
+    assert_eq!(
+        Duration::new(u64::MAX - 60, 0).add_minutes_checked(1),
+        Some(Duration::from_secs(u64::MAX))
+    );
+    assert_eq!(Duration::new(u64::MAX - 59, 0).add_minutes_checked(1), None);
+    assert_eq!(Duration::MAX.add_days_checked(0), Some(Duration::MAX));
+}
+
 #[test]
 fn saturating_add() {
     assert_eq!(Duration::new(0, 0).saturating_add(Duration::new(0, 1)), Duration::new(0, 1));
@@ -99,6 +317,47 @@ fn saturating_add() {
     assert_eq!(Duration::new(1, 0).saturating_add(Duration::new(u64::MAX, 0)), Duration::MAX);
 }
 
+#[test]
+fn saturating_add_secs() {
+    assert_eq!(Duration::ZERO.saturating_add_secs(0), Duration::ZERO);
+    assert_eq!(Duration::new(0, 999_999_999).saturating_add_secs(1), Duration::new(1, 999_999_999));
+    assert_eq!(Duration::ZERO.saturating_add_secs(u64::MAX), Duration::new(u64::MAX, 0));
+    assert_eq!(Duration::new(0, 1).saturating_add_secs(u64::MAX), Duration::new(u64::MAX, 1));
+    assert_eq!(Duration::new(1, 0).saturating_add_secs(u64::MAX), Duration::MAX);
+    assert_eq!(Duration::MAX.saturating_add_secs(0), Duration::MAX);
+}
+
+#[test]
+fn wrapping_add_nanos() {
+    assert_eq!(Duration::ZERO.wrapping_add_nanos(0), Duration::ZERO);
+    assert_eq!(Duration::new(1, 999_999_999).wrapping_add_nanos(1), Duration::new(2, 0));
+    assert_eq!(Duration::ZERO.wrapping_add_nanos(u64::MAX), Duration::from_nanos(u64::MAX));
+    assert_eq!(Duration::MAX.wrapping_add_nanos(0), Duration::MAX);
+    assert_eq!(Duration::MAX.wrapping_add_nanos(1), Duration::ZERO);
+    assert_eq!(Duration::MAX.wrapping_add_nanos(2), Duration::NANOSECOND);
+    assert_eq!(Duration::new(u64::MAX, 0).wrapping_add_nanos(2_000_000_001), Duration::new(1, 1));
+    assert_eq!(
+        Duration::MAX.wrapping_add_nanos(u64::MAX),
+        Duration::from_nanos(u64::MAX) - Duration::NANOSECOND
+    );
+}
+
+#[test]
+fn elapsed_nanos_since() {
+    assert_eq!(Duration::ZERO.elapsed_nanos_since(Duration::ZERO), 0);
+    assert_eq!(Duration::MAX.elapsed_nanos_since(Duration::MAX), 0);
+    assert_eq!(Duration::new(2, 1).elapsed_nanos_since(Duration::new(1, 2)), 999_999_999);
+    assert_eq!(Duration::ZERO.elapsed_nanos_since(Duration::MAX), 1);
+    assert_eq!(Duration::ZERO.elapsed_nanos_since(Duration::NANOSECOND), u64::MAX);
+    assert_eq!(Duration::new(1, 0).elapsed_nanos_since(Duration::new(u64::MAX, 1)), 1_999_999_999);
+    assert_eq!(Duration::from_nanos(u64::MAX).elapsed_nanos_since(Duration::ZERO), u64::MAX);
+    assert_eq!(Duration::MAX.elapsed_nanos_since(Duration::ZERO), u64::MAX);
+
+    let start = Duration::new(u64::MAX, 999_000_000);
+    let elapsed = 123_456_789_012;
+    assert_eq!(start.wrapping_add_nanos(elapsed).elapsed_nanos_since(start), elapsed);
+}
+
 #[test]
 fn sub() {
     assert_eq!(Duration::new(0, 1) - Duration::new(0, 0), Duration::new(0, 1));
@@ -117,6 +376,21 @@ fn checked_sub() {
     assert_eq!(Duration::ZERO.checked_sub(Duration::SECOND), None);
 }
 
+#[test]
+fn try_sub() {
+    assert_eq!(Duration::NANOSECOND.try_sub(Duration::ZERO), Ok(Duration::NANOSECOND));
+    assert_eq!(Duration::MAX.try_sub(Duration::MAX), Ok(Duration::ZERO));
+    assert_eq!(Duration::SECOND.try_sub(Duration::NANOSECOND), Ok(Duration::new(0, 999_999_999)));
+
+    let err = Duration::ZERO.try_sub(Duration::NANOSECOND).unwrap_err();
+    assert_eq!(err.magnitude(), Duration::NANOSECOND);
+    assert_eq!(format!("{err}"), "duration underflowed by 1ns");
+    let err = Duration::new(1, 1).try_sub(Duration::new(2, 0)).unwrap_err();
+    assert_eq!(err.magnitude(), Duration::new(0, 999_999_999));
+    let err = Duration::ZERO.try_sub(Duration::MAX).unwrap_err();
+    assert_eq!(err.magnitude(), Duration::MAX);
+}
+
 #[test]
 fn saturating_sub() {
     assert_eq!(Duration::NANOSECOND.saturating_sub(Duration::ZERO), Duration::NANOSECOND);
@@ -157,6 +431,100 @@ fn checked_mul() {
     assert_eq!(Duration::new(u64::MAX - 1, 0).checked_mul(2), None);
 }
 
+#[test]
+fn checked_mul_add() {
+    assert_eq!(Duration::ZERO.checked_mul_add(0, Duration::ZERO), Some(Duration::ZERO));
+    assert_eq!(
+        Duration::new(1, 1).checked_mul_add(3, Duration::new(0, 1)),
+        Some(Duration::new(3, 4))
+    );
+    assert_eq!(
+        Duration::new(0, 500_000_001).checked_mul_add(2, Duration::new(0, 999_999_998)),
+        Some(Duration::new(2, 0))
+    );
+    assert_eq!(
+        Duration::new(u64::MAX / 2, 0).checked_mul_add(2, Duration::new(0, 999_999_999)),
+        Some(Duration::new(u64::MAX - 1, 999_999_999))
+    );
+    assert_eq!(
+        Duration::new(u64::MAX / 2, 0).checked_mul_add(2, Duration::new(1, 0)),
+        Some(Duration::new(u64::MAX, 0))
+    );
+    assert_eq!(Duration::new(u64::MAX / 2, 0).checked_mul_add(2, Duration::new(2, 0)), None);
+    assert_eq!(Duration::new(u64::MAX - 1, 0).checked_mul_add(2, Duration::ZERO), None);
+    assert_eq!(Duration::MAX.checked_mul_add(0, Duration::MAX), Some(Duration::MAX));
+}
+
+#[test]
+fn checked_mul_u64() {
+    assert_eq!(Duration::new(0, 1).checked_mul_u64(2), Some(Duration::new(0, 2)));
+    assert_eq!(Duration::new(1, 1).checked_mul_u64(0), Some(Duration::ZERO));
+    assert_eq!(Duration::new(0, 500_000_001).checked_mul_u64(4), Some(Duration::new(2, 4)));
+    assert_eq!(
+        Duration::NANOSECOND.checked_mul_u64(u64::MAX),
+        Some(Duration::from_nanos(u64::MAX))
+    );
+    assert_eq!(Duration::SECOND.checked_mul_u64(u64::MAX), Some(Duration::new(u64::MAX, 0)));
+    assert_eq!(Duration::MAX.checked_mul_u64(1), Some(Duration::MAX));
+    assert_eq!(Duration::new(1, 1).checked_mul_u64(u64::MAX), None);
+    assert_eq!(Duration::new(u64::MAX - 1, 0).checked_mul_u64(2), None);
+    assert_eq!(Duration::MAX.checked_mul_u64(u64::MAX), None);
+    for rhs in [0, 1, 7, 4_000, u32::MAX as u64] {
+        let d = Duration::new(123, 456_789);
+        assert_eq!(d.checked_mul_u64(rhs), d.checked_mul(rhs as u32));
+    }
+}
+
+#[test]
+fn checked_double() {
+    assert_eq!(Duration::ZERO.checked_double(), Some(Duration::ZERO));
+    assert_eq!(Duration::new(0, 500_000_000).checked_double(), Some(Duration::new(1, 0)));
+    assert_eq!(Duration::new(1, 999_999_999).checked_double(), Some(Duration::new(3, 999_999_998)));
+    assert_eq!(
+        Duration::new(u64::MAX / 2, 999_999_999).checked_double(),
+        Some(Duration::new(u64::MAX, 999_999_998))
+    );
+    assert_eq!(Duration::new(u64::MAX / 2 + 1, 0).checked_double(), None);
+    assert_eq!(Duration::MAX.checked_double(), None);
+}
+
+#[test]
+fn half() {
+    assert_eq!(Duration::ZERO.half(), Duration::ZERO);
+    assert_eq!(Duration::NANOSECOND.half(), Duration::ZERO);
+    assert_eq!(Duration::new(1, 0).half(), Duration::new(0, 500_000_000));
+    assert_eq!(Duration::new(3, 999_999_999).half(), Duration::new(1, 999_999_999));
+    assert_eq!(Duration::MAX.half(), Duration::MAX / 2);
+    for d in [Duration::new(7, 123_456_789), Duration::new(u64::MAX - 1, 1), Duration::MAX] {
+        assert_eq!(d.half(), d / 2);
+    }
+}
+
+#[test]
+fn quarter() {
+    assert_eq!(Duration::ZERO.quarter(), Duration::ZERO);
+    assert_eq!(Duration::from_nanos(3).quarter(), Duration::ZERO);
+    assert_eq!(Duration::new(1, 0).quarter(), Duration::new(0, 250_000_000));
+    assert_eq!(Duration::new(7, 999_999_999).quarter(), Duration::new(1, 999_999_999));
+    for d in [Duration::new(7, 123_456_789), Duration::new(u64::MAX - 1, 1), Duration::MAX] {
+        assert_eq!(d.quarter(), d / 4);
+        assert_eq!(d.quarter(), d.half().half());
+    }
+}
+
+#[test]
+fn double() {
+    assert_eq!(Duration::ZERO.double(), Duration::ZERO);
+    assert_eq!(Duration::new(1, 600_000_000).double(), Duration::new(3, 200_000_000));
+    assert_eq!(Duration::MAX.half().double(), Duration::MAX - Duration::NANOSECOND);
+}
+
+#[test]
+#[should_panic(expected = "overflow when doubling duration")]
+fn double_overflow() {
+    let _ = Duration::MAX.double();
+}
+
 #[test]
 fn saturating_mul() {
     assert_eq!(Duration::new(0, 1).saturating_mul(2), Duration::new(0, 2));
@@ -204,6 +572,33 @@ fn checked_div() {
     assert_eq!(Duration::new(2, 0).checked_div(2), Some(Duration::new(1, 0)));
     assert_eq!(Duration::new(1, 0).checked_div(2), Some(Duration::new(0, 500_000_000)));
     assert_eq!(Duration::new(2, 0).checked_div(0), None);
+    assert_eq!(Duration::new(1, 2).checked_div(3), Some(Duration::new(0, 333_333_334)));
+}
+
+#[test]
+fn checked_div_boundaries() {
+    let durations = [
+        Duration::ZERO,
+        Duration::NANOSECOND,
+        Duration::new(0, 999_999_999),
+        Duration::SECOND,
+        Duration::new(1, 999_999_999),
+        Duration::new(u32::MAX as u64, 999_999_999),
+        Duration::new(u64::MAX - 1, 0),
+        Duration::new(u64::MAX - 1, 999_999_999),
+        Duration::new(u64::MAX, 0),
+        Duration::MAX,
+    ];
+    let divisors = [1, 2, 3, 7, 999_999_999, 1_000_000_000, 1_000_000_001, u32::MAX - 1, u32::MAX];
+    for d in durations {
+        assert_eq!(d.checked_div(1), Some(d));
+        assert_eq!(d.checked_div(0), None);
+        for rhs in divisors {
+            // Division is exact floor division of the total number of nanoseconds.
+            let expected = Duration::from_nanos_u128(d.as_nanos() / rhs as u128);
+            assert_eq!(d.checked_div(rhs), expected, "{d:?} / {rhs}");
+        }
+    }
 }
 
 #[test]
@@ -220,6 +615,35 @@ fn correct_sum() {
     assert_eq!(sum, Duration::new(1 + 2 + 5 + 4, 1_000_000_000 - 5));
 }
 
+#[test]
+fn checked_sum() {
+    let durations = [
+        Duration::new(1, 999_999_999),
+        Duration::new(2, 999_999_999),
+        Duration::new(0, 999_999_999),
+        Duration::new(5, 0),
+    ];
+    let sum = |durations: &[Duration]| Duration::checked_sum_iter(durations.iter().copied());
+    assert_eq!(sum(&durations), Some(durations.iter().sum()));
+    assert_eq!(sum(&[]), Some(Duration::ZERO));
+
+    assert_eq!(sum(&[Duration::MAX]), Some(Duration::MAX));
+    assert_eq!(sum(&[Duration::MAX, Duration::NANOSECOND]), None);
+    assert_eq!(sum(&[Duration::new(u64::MAX, 0), Duration::SECOND]), None);
+    let halves = [Duration::new(u64::MAX, 500_000_000), Duration::new(0, 500_000_000)];
+    assert_eq!(sum(&halves), None);
+
+    assert_eq!(Duration::checked_sum_iter(durations.into_iter()), Some(durations.iter().sum()));
+    assert_eq!(Duration::checked_sum_iter(core::iter::empty()), Some(Duration::ZERO));
+    assert_eq!(Duration::checked_sum_iter(halves.into_iter()), None);
+}
+
+#[test]
+#[should_panic(expected = "overflow in iter::sum over durations")]
+fn sum_overflow() {
+    let _ = [Duration::MAX, Duration::NANOSECOND].iter().sum::<Duration>();
+}
+
 #[test]
 fn debug_formatting_extreme_values() {
     assert_eq!(
@@ -309,7 +733,7 @@ fn debug_formatting_micros() {
 
 #[test]
 fn debug_formatting_nanos() {
-    assert_eq!(format!("{:?}", Duration::new(0, 0)), "0ns");
+    assert_eq!(format!("{:?}", Duration::new(0, 0)), "0s");
     assert_eq!(format!("{:?}", Duration::new(0, 1)), "1ns");
     assert_eq!(format!("{:?}", Duration::new(0, 88)), "88ns");
     assert_eq!(format!("{:?}", Duration::new(0, 999)), "999ns");
@@ -317,7 +741,7 @@ fn debug_formatting_nanos() {
 
 #[test]
 fn debug_formatting_precision_zero() {
-    assert_eq!(format!("{:.0?}", Duration::new(0, 0)), "0ns");
+    assert_eq!(format!("{:.0?}", Duration::new(0, 0)), "0s");
     assert_eq!(format!("{:.0?}", Duration::new(0, 123)), "123ns");
 
     assert_eq!(format!("{:.0?}", Duration::new(0, 1_001)), "1µs");
@@ -338,7 +762,7 @@ fn debug_formatting_precision_zero() {
 
 #[test]
 fn debug_formatting_precision_two() {
-    assert_eq!(format!("{:.2?}", Duration::new(0, 0)), "0.00ns");
+    assert_eq!(format!("{:.2?}", Duration::new(0, 0)), "0.00s");
     assert_eq!(format!("{:.2?}", Duration::new(0, 123)), "123.00ns");
 
     assert_eq!(format!("{:.2?}", Duration::new(0, 1_000)), "1.00µs");
@@ -363,9 +787,9 @@ fn debug_formatting_precision_two() {
 
 #[test]
 fn debug_formatting_padding() {
-    assert_eq!("0ns      ", format!("{:<9?}", Duration::new(0, 0)));
-    assert_eq!("      0ns", format!("{:>9?}", Duration::new(0, 0)));
-    assert_eq!("   0ns   ", format!("{:^9?}", Duration::new(0, 0)));
+    assert_eq!("0s       ", format!("{:<9?}", Duration::new(0, 0)));
+    assert_eq!("       0s", format!("{:>9?}", Duration::new(0, 0)));
+    assert_eq!("   0s    ", format!("{:^9?}", Duration::new(0, 0)));
     assert_eq!("123ns    ", format!("{:<9.0?}", Duration::new(0, 123)));
     assert_eq!("    123ns", format!("{:>9.0?}", Duration::new(0, 123)));
     assert_eq!("  123ns  ", format!("{:^9.0?}", Duration::new(0, 123)));
@@ -501,3 +925,1190 @@ fn from_neg_zero() {
     assert_eq!(Duration::from_secs_f32(-0.0), Duration::ZERO);
     assert_eq!(Duration::from_secs_f64(-0.0), Duration::ZERO);
 }
+
+#[test]
+fn variance_nanos() {
+    assert_eq!(Duration::variance_nanos(&[]), None);
+    assert_eq!(Duration::variance_nanos(&[Duration::MAX]), None);
+    assert_eq!(Duration::variance_nanos(&[Duration::SECOND; 10]), Some(0.0));
+    assert_eq!(Duration::variance_nanos(&[Duration::ZERO, Duration::from_nanos(2)]), Some(1.0));
+    let samples = [Duration::new(1_000, 1), Duration::new(1_000, 3)];
+    assert_eq!(Duration::variance_nanos(&samples), Some(1.0));
+}
+
+#[test]
+fn weighted_average() {
+    assert_eq!(Duration::weighted_average(&[]), None);
+    assert_eq!(Duration::weighted_average(&[(Duration::SECOND, 0), (Duration::MAX, 0)]), None);
+    assert_eq!(Duration::weighted_average(&[(Duration::SECOND, 7)]), Some(Duration::SECOND));
+    let samples = [(Duration::ZERO, 1), (Duration::from_nanos(10), 2), (Duration::MAX, 0)];
+    assert_eq!(Duration::weighted_average(&samples), Some(Duration::from_nanos(6)));
+    let samples = [(Duration::MAX, u32::MAX), (Duration::MAX, u32::MAX)];
+    assert_eq!(Duration::weighted_average(&samples), Some(Duration::MAX));
+    let samples = [(Duration::MAX, u32::MAX); 5];
+    assert_eq!(Duration::weighted_average(&samples), None);
+}
+
+#[test]
+fn interquartile_range() {
+    let ns = |nanos: &[u64]| nanos.iter().copied().map(Duration::from_nanos).collect::<Vec<_>>();
+    assert_eq!(Duration::interquartile_range(&[]), None);
+    assert_eq!(Duration::interquartile_range(&ns(&[1, 2, 3])), None);
+    assert_eq!(Duration::interquartile_range(&ns(&[1, 2, 3, 4])), Some(Duration::from_nanos(2)));
+    assert_eq!(Duration::interquartile_range(&ns(&[1, 2, 3, 4, 5])), Some(Duration::from_nanos(3)));
+    assert_eq!(
+        Duration::interquartile_range(&ns(&[1, 2, 3, 4, 6, 8])),
+        Some(Duration::from_nanos(4))
+    );
+    assert_eq!(
+        Duration::interquartile_range(&ns(&[0, 1, 2, 4, 4, 5, 5])),
+        Some(Duration::from_nanos(4))
+    );
+    assert_eq!(Duration::interquartile_range(&[Duration::SECOND; 8]), Some(Duration::ZERO));
+    let extremes = [Duration::ZERO, Duration::ZERO, Duration::MAX, Duration::MAX];
+    assert_eq!(Duration::interquartile_range(&extremes), Some(Duration::MAX));
+    let extremes = [Duration::ZERO, Duration::MAX, Duration::MAX, Duration::MAX, Duration::MAX];
+    assert_eq!(Duration::interquartile_range(&extremes), Some(Duration::MAX - Duration::MAX / 2));
+}
+
+#[test]
+fn median() {
+    let ns = |nanos: &[u64]| nanos.iter().copied().map(Duration::from_nanos).collect::<Vec<_>>();
+    assert_eq!(Duration::median(&[]), None);
+    assert_eq!(Duration::median(&[Duration::MAX]), Some(Duration::MAX));
+    assert_eq!(Duration::median(&ns(&[1, 2])), Some(Duration::from_nanos(1)));
+    assert_eq!(Duration::median(&ns(&[1, 3])), Some(Duration::from_nanos(2)));
+    assert_eq!(Duration::median(&ns(&[1, 2, 100])), Some(Duration::from_nanos(2)));
+    assert_eq!(Duration::median(&ns(&[1, 2, 4, 100])), Some(Duration::from_nanos(3)));
+    assert_eq!(Duration::median(&[Duration::MAX, Duration::MAX]), Some(Duration::MAX));
+    assert_eq!(Duration::median(&[Duration::ZERO, Duration::MAX]), Some(Duration::MAX / 2));
+}
+
+#[test]
+fn percentile() {
+    let ns = |nanos: &[u64]| nanos.iter().copied().map(Duration::from_nanos).collect::<Vec<_>>();
+    let samples = ns(&[10, 20, 30, 40, 50]);
+    assert_eq!(Duration::percentile(&samples, 0.0), Some(Duration::from_nanos(10)));
+    assert_eq!(Duration::percentile(&samples, 12.5), Some(Duration::from_nanos(15)));
+    assert_eq!(Duration::percentile(&samples, 25.0), Some(Duration::from_nanos(20)));
+    assert_eq!(Duration::percentile(&samples, 50.0), Some(Duration::from_nanos(30)));
+    assert_eq!(Duration::percentile(&samples, 90.0), Some(Duration::from_nanos(46)));
+    assert_eq!(Duration::percentile(&samples, 100.0), Some(Duration::from_nanos(50)));
+    assert_eq!(Duration::percentile(&samples, -0.1), None);
+    assert_eq!(Duration::percentile(&samples, 100.1), None);
+    assert_eq!(Duration::percentile(&samples, f64::NAN), None);
+    assert_eq!(Duration::percentile(&[], 50.0), None);
+
+    assert_eq!(Duration::percentile(&[Duration::MAX], 37.0), Some(Duration::MAX));
+    let extremes = [Duration::ZERO, Duration::MAX];
+    assert_eq!(Duration::percentile(&extremes, 100.0), Some(Duration::MAX));
+    assert!(Duration::percentile(&extremes, 99.999).unwrap() < Duration::MAX);
+    for samples in [ns(&[1, 2]), ns(&[1, 4]), ns(&[1, 2, 4, 100]), ns(&[5, 5, 6])] {
+        assert_eq!(Duration::percentile(&samples, 50.0), Duration::median(&samples));
+    }
+}
+
+#[test]
+fn bucketize() {
+    let ms = Duration::from_millis;
+    assert_eq!(Duration::ZERO.bucketize(ms(10)), 0);
+    assert_eq!((ms(10) - Duration::NANOSECOND).bucketize(ms(10)), 0);
+    assert_eq!(ms(10).bucketize(ms(10)), 1);
+    assert_eq!(ms(1_005).bucketize(ms(10)), 100);
+    assert_eq!(ms(5).bucketize(Duration::MAX), 0);
+    assert_eq!(Duration::MAX.bucketize(Duration::MAX), 1);
+    assert_eq!(Duration::MAX.bucketize(Duration::NANOSECOND), Duration::MAX.as_nanos());
+}
+
+#[test]
+#[should_panic]
+fn bucketize_zero() {
+    let _ = Duration::SECOND.bucketize(Duration::ZERO);
+}
+
+#[test]
+fn to_protobuf_checked() {
+    assert_eq!(Duration::ZERO.to_protobuf_checked(), Some((0, 0)));
+    assert_eq!(Duration::new(1, 999_999_999).to_protobuf_checked(), Some((1, 999_999_999)));
+    assert_eq!(
+        Duration::new(i64::MAX as u64, 999_999_999).to_protobuf_checked(),
+        Some((i64::MAX, 999_999_999))
+    );
+    assert_eq!(Duration::new(i64::MAX as u64 + 1, 0).to_protobuf_checked(), None);
+    assert_eq!(Duration::MAX.to_protobuf_checked(), None);
+}
+
+#[test]
+fn u96_le() {
+    assert_eq!(Duration::ZERO.to_u96_le(), [0; 12]);
+    assert_eq!(
+        Duration::MAX.to_u96_le(),
+        [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xc9, 0x9a, 0x3b]
+    );
+    for duration in
+        [Duration::ZERO, Duration::new(1, 1), Duration::new(12_345, 678_901), Duration::MAX]
+    {
+        assert_eq!(Duration::from_u96_le(duration.to_u96_le()), Some(duration));
+    }
+    assert_eq!(Duration::from_u96_le([0, 0, 0, 0, 0, 0, 0, 0, 0x00, 0xca, 0x9a, 0x3b]), None);
+    assert_eq!(Duration::from_u96_le([0xff; 12]), None);
+}
+
+#[test]
+fn from_hex_str() {
+    assert_eq!(Duration::from_hex_str("0:0"), Ok(Duration::ZERO));
+    assert_eq!(Duration::from_hex_str("00000000000000ff:0000000f"), Ok(Duration::new(255, 15)));
+    assert_eq!(Duration::from_hex_str("aBcD:Ef"), Ok(Duration::new(0xabcd, 0xef)));
+    assert_eq!(Duration::from_hex_str("ffffffffffffffff:3b9ac9ff"), Ok(Duration::MAX));
+
+    for invalid in
+        ["", ":", "0:", ":0", "0", "0:0:0", "+1:0", "1:-0", " 1:0", "1:0 ", "g:0", "1.0:0"]
+    {
+        assert!(Duration::from_hex_str(invalid).is_err(), "{invalid:?}");
+    }
+    assert!(Duration::from_hex_str("10000000000000000:0").is_err());
+    assert!(Duration::from_hex_str("0:3b9aca00").is_err());
+    assert!(Duration::from_hex_str("0:100000000").is_err());
+}
+
+#[test]
+fn duration_range() {
+    let range = DurationRange { start: Duration::from_millis(100), end: Duration::from_secs(2) };
+    assert!(!range.contains(Duration::ZERO));
+    assert!(range.contains(Duration::from_millis(100)));
+    assert!(range.contains(Duration::new(1, 999_999_999)));
+    assert!(!range.contains(Duration::from_secs(2)));
+    assert!(!range.contains(Duration::MAX));
+    assert_eq!(range.len(), Duration::from_millis(1_900));
+    assert!(!range.is_empty());
+    assert_eq!(range.clamp(Duration::ZERO), Duration::from_millis(100));
+    assert_eq!(range.clamp(Duration::SECOND), Duration::SECOND);
+    assert_eq!(range.clamp(Duration::MAX), Duration::new(1, 999_999_999));
+    assert_eq!(format!("{range:?}"), "100ms..2s");
+
+    let single = DurationRange { start: Duration::ZERO, end: Duration::NANOSECOND };
+    assert_eq!(single.len(), Duration::NANOSECOND);
+    assert_eq!(single.clamp(Duration::MAX), Duration::ZERO);
+
+    for empty in [
+        DurationRange::default(),
+        DurationRange { start: Duration::MAX, end: Duration::MAX },
+        DurationRange { start: Duration::SECOND, end: Duration::ZERO },
+    ] {
+        assert!(empty.is_empty());
+        assert_eq!(empty.len(), Duration::ZERO);
+        assert!(!empty.contains(empty.start));
+        assert!(!empty.contains(empty.end));
+    }
+}
+
+#[test]
+#[should_panic]
+fn duration_range_clamp_empty() {
+    let _ =
+        DurationRange { start: Duration::SECOND, end: Duration::SECOND }.clamp(Duration::SECOND);
+}
+
+#[test]
+fn parse_human_readable() {
+    let parse = Duration::parse_human_readable;
+    assert_eq!(parse("0 seconds"), Ok(Duration::ZERO));
+    assert_eq!(parse("1 nanosecond"), Ok(Duration::NANOSECOND));
+    assert_eq!(parse("7 nanoseconds 3 microseconds"), Ok(Duration::from_nanos(3_007)));
+    assert_eq!(parse("2 milliseconds"), Ok(Duration::from_millis(2)));
+    assert_eq!(parse("  1 second\t2  minutes\n"), Ok(Duration::from_secs(121)));
+    assert_eq!(parse("1 hour 1 day 1 week"), Ok(Duration::from_secs(3_600 * (1 + 24 + 168))));
+    assert_eq!(parse("1 second 1 second"), Ok(Duration::from_secs(2)));
+    assert_eq!(parse("0.5 seconds"), Ok(Duration::from_millis(500)));
+    assert_eq!(parse(".5 seconds"), Ok(Duration::from_millis(500)));
+    assert_eq!(parse("5. seconds"), Ok(Duration::from_secs(5)));
+    assert_eq!(parse("0.000000001 seconds"), Ok(Duration::NANOSECOND));
+    assert_eq!(parse("0.0000000019 seconds"), Ok(Duration::NANOSECOND));
+    assert_eq!(parse("1.000000000000000000000001 weeks"), Ok(Duration::from_secs(604_800)));
+    assert_eq!(parse("0.1 nanoseconds"), Ok(Duration::ZERO));
+    assert_eq!(parse("18446744073709551615.999999999 seconds"), Ok(Duration::MAX));
+    assert_eq!(parse("018446744073709551615 seconds 999999999 nanoseconds"), Ok(Duration::MAX));
+
+    for invalid in [
+        "",
+        " ",
+        "5",
+        "seconds",
+        "5 seconds 3",
+        "5seconds",
+        ". seconds",
+        "1.2.3 seconds",
+        "-1 seconds",
+        "+1 seconds",
+        "1e3 seconds",
+        "1 second,",
+        "5 fortnights",
+        "5 Seconds",
+        "5 s",
+    ] {
+        assert!(parse(invalid).is_err(), "{invalid:?}");
+    }
+    assert!(parse("18446744073709551616 seconds").is_err());
+    assert!(parse("18446744073709551615 seconds 1 second").is_err());
+    assert!(parse("1000000000000000000000000000000000000000 nanoseconds").is_err());
+
+    let span = |s| parse(s).unwrap_err().unknown_unit_span();
+    assert_eq!(span("5 fortnights"), Some(2..12));
+    assert_eq!(span(" 1 second\t2  Seconds "), Some(13..20));
+    assert_eq!(span("1 µs"), Some(2..5));
+    assert_eq!(span("5"), None);
+    assert_eq!(span("18446744073709551616 seconds"), None);
+}
+
+#[test]
+fn parse_one_past_u128_max() {
+    // `u128::MAX + 1` overflows when its last digit is added, not when multiplying by 10.
+    let overflow = Duration::parse_human_readable("18446744073709551616 seconds").unwrap_err();
+    let s = "340282366920938463463374607431768211456";
+    assert_eq!(Duration::parse_human_readable(&format!("{s} nanoseconds")), Err(overflow.clone()));
+    assert_eq!(Duration::from_str_flexible(&format!("{s}ns")), Err(overflow.clone()));
+    assert_eq!(Duration::from_str_flexible(s), Err(overflow.clone()));
+    assert_eq!(Duration::from_sql_interval(&format!("{s} secs")), Err(overflow));
+}
+
+#[test]
+fn from_str_flexible() {
+    let parse = Duration::from_str_flexible;
+    assert_eq!(parse("PT5S"), Ok(Duration::from_secs(5)));
+    assert_eq!(parse("PT1.5S"), Ok(Duration::from_millis(1_500)));
+    assert_eq!(parse("P1W"), Ok(Duration::from_secs(604_800)));
+    assert_eq!(parse("P1DT2H30M"), Ok(Duration::from_secs(86_400 + 9_000)));
+    assert_eq!(parse("P1W1DT1H1M1S"), Ok(Duration::from_secs(691_200 + 3_661)));
+    assert_eq!(parse("5 minutes 30 seconds"), Ok(Duration::from_secs(330)));
+    assert_eq!(parse("5s"), Ok(Duration::from_secs(5)));
+    assert_eq!(parse("1h30m"), Ok(Duration::from_secs(5_400)));
+    assert_eq!(parse("30m1h"), Ok(Duration::from_secs(5_400)));
+    assert_eq!(parse("1d1w"), Ok(Duration::from_secs(691_200)));
+    assert_eq!(parse("1.5ms"), Ok(Duration::from_micros(1_500)));
+    assert_eq!(parse("2us3µs4ns"), Ok(Duration::from_nanos(5_004)));
+    assert_eq!(parse("5"), Ok(Duration::from_secs(5)));
+    assert_eq!(parse(" 5.0\n"), Ok(Duration::from_secs(5)));
+    assert_eq!(parse(".000000001"), Ok(Duration::NANOSECOND));
+    assert_eq!(parse("18446744073709551615.999999999"), Ok(Duration::MAX));
+
+    let invalid = parse("").unwrap_err();
+    for s in ["P", "PT", "P1", "PT1", "PT5S1", "PT1S1H", "P1D1W", "P1DT", "pt5s", "-5s", "s"] {
+        assert_eq!(parse(s), Err(invalid.clone()), "{s:?}");
+    }
+    let unknown_unit = parse("5 fortnights").unwrap_err();
+    assert_ne!(unknown_unit, invalid);
+    assert_eq!(unknown_unit.unknown_unit_span(), Some(2..12));
+    for (s, span) in [
+        ("P1Y", 2..3),
+        ("P1M", 2..3),
+        ("PT1D", 3..4),
+        ("5x", 1..2),
+        ("5 s", 2..3),
+        ("1h30min", 4..7),
+        (" \t1h30min", 6..9),
+    ] {
+        assert_eq!(parse(s).unwrap_err().unknown_unit_span(), Some(span), "{s:?}");
+    }
+    let overflow = parse("18446744073709551616").unwrap_err();
+    assert_ne!(overflow, invalid);
+    assert_ne!(overflow, unknown_unit);
+    for s in [
+        "PT18446744073709551616S",
+        "P31000000000000W",
+        "31000000000000w",
+        "18446744073709551615s1s",
+    ] {
+        assert_eq!(parse(s), Err(overflow.clone()), "{s:?}");
+    }
+}
+
+#[test]
+fn from_sql_interval() {
+    let parse = Duration::from_sql_interval;
+    let secs = Duration::from_secs;
+    assert_eq!(parse("00:00:00"), Ok(Duration::ZERO));
+    assert_eq!(parse("00:00:05"), Ok(secs(5)));
+    assert_eq!(parse(" 04:05:06.789 "), Ok(Duration::from_millis(14_706_789)));
+    assert_eq!(parse("100:00:00"), Ok(secs(360_000)));
+    assert_eq!(parse("3 days"), Ok(secs(259_200)));
+    assert_eq!(parse("1 day 00:00:01"), Ok(secs(86_401)));
+    assert_eq!(parse("0 years 0 mons 3 days 04:05:06"), Ok(secs(259_200 + 14_706)));
+    assert_eq!(parse("0 years 0 mons 0 days 0 hours 0 mins 5.000000 secs"), Ok(secs(5)));
+    assert_eq!(parse("@ 1 day 2 hours 3 mins 4.5 secs"), Ok(Duration::from_millis(93_784_500)));
+    assert_eq!(parse("@ 1 min"), Ok(secs(60)));
+    assert_eq!(parse("PT0S"), Ok(Duration::ZERO));
+    assert_eq!(parse("PT5S"), Ok(secs(5)));
+    assert_eq!(parse("P0Y0M3DT4H5M6.5S"), Ok(Duration::from_millis(273_906_500)));
+    assert_eq!(parse("P1W"), Ok(secs(604_800)));
+    assert_eq!(parse("00:00:59.999999999999"), Ok(Duration::new(59, 999_999_999)));
+
+    let invalid = parse("").unwrap_err();
+    for s in [
+        "@",
+        "5",
+        "1:00",
+        "1:00:00:00",
+        "00:60:00",
+        "00:00:60",
+        "0:0:05",
+        "00:0.5:00",
+        "0.5:00:00",
+        "-00:00:05",
+        "00:00:05 1 day",
+        "1 mon",
+        "0.1 years",
+        "-1 days +02:00:00",
+        "@ 5 secs ago",
+        "P1Y",
+        "P1M",
+        "P-1D",
+    ] {
+        assert_eq!(parse(s), Err(invalid.clone()), "{s:?}");
+    }
+    let unknown_unit = parse("5 seconds").unwrap_err();
+    assert_ne!(unknown_unit, invalid);
+    assert_eq!(unknown_unit.unknown_unit_span(), Some(2..9));
+    assert_eq!(parse("1 week").unwrap_err().unknown_unit_span(), Some(2..6));
+    assert_eq!(parse(" @ 1 hr").unwrap_err().unknown_unit_span(), Some(5..7));
+    assert_eq!(parse(" PT1X").unwrap_err().unknown_unit_span(), Some(4..5));
+    let overflow = parse("18446744073709551616 secs").unwrap_err();
+    assert_ne!(overflow, invalid);
+    assert_eq!(parse("5124095576030432:00:00"), Err(overflow.clone()));
+    assert_eq!(parse("PT18446744073709551616S"), Err(overflow));
+}
+
+#[test]
+fn as_secs_ceiling() {
+    assert_eq!(Duration::ZERO.as_secs_ceiling(), 0);
+    assert_eq!(Duration::NANOSECOND.as_secs_ceiling(), 1);
+    assert_eq!(Duration::new(1, 0).as_secs_ceiling(), 1);
+    assert_eq!(Duration::new(1, 1).as_secs_ceiling(), 2);
+    assert_eq!(Duration::new(1, 999_999_999).as_secs_ceiling(), 2);
+    assert_eq!(Duration::new(u64::MAX, 0).as_secs_ceiling(), u64::MAX);
+    assert_eq!(Duration::MAX.as_secs_ceiling(), u64::MAX);
+}
+
+#[test]
+fn as_secs_round() {
+    assert_eq!(Duration::ZERO.as_secs_round(), 0);
+    assert_eq!(Duration::new(0, 499_999_999).as_secs_round(), 0);
+    assert_eq!(Duration::new(0, 500_000_000).as_secs_round(), 1);
+    assert_eq!(Duration::new(1, 0).as_secs_round(), 1);
+    assert_eq!(Duration::new(1, 999_999_999).as_secs_round(), 2);
+    assert_eq!(Duration::new(u64::MAX, 499_999_999).as_secs_round(), u64::MAX);
+    assert_eq!(Duration::MAX.as_secs_round(), u64::MAX);
+}
+
+#[test]
+fn as_secs_u128() {
+    assert_eq!(Duration::ZERO.as_secs_u128(), 0);
+    assert_eq!(Duration::new(5, 999_999_999).as_secs_u128(), 5);
+    assert_eq!(Duration::MAX.as_secs_u128(), u64::MAX as u128);
+}
+
+#[test]
+fn secs_i64() {
+    assert_eq!(Duration::from_secs_i64(0), Some(Duration::ZERO));
+    assert_eq!(Duration::from_secs_i64(30), Some(Duration::from_secs(30)));
+    assert_eq!(Duration::from_secs_i64(-30), None);
+
+    assert_eq!(Duration::ZERO.to_secs_i64(), Some(0));
+    assert_eq!(Duration::new(30, 999_999_999).to_secs_i64(), Some(30));
+    assert_eq!(Duration::MAX.to_secs_i64(), None);
+
+    assert_eq!(Duration::from_secs_i64(-1), None);
+    assert_eq!(Duration::from_secs_i64(i64::MIN), None);
+    assert_eq!(Duration::from_secs_i64(i64::MAX), Some(Duration::from_secs(i64::MAX as u64)));
+    assert_eq!(Duration::new(i64::MAX as u64, 999_999_999).to_secs_i64(), Some(i64::MAX));
+    assert_eq!(Duration::new(i64::MAX as u64 + 1, 0).to_secs_i64(), None);
+    for secs in [0, 1, i64::MAX - 1, i64::MAX] {
+        assert_eq!(Duration::from_secs_i64(secs).and_then(|d| d.to_secs_i64()), Some(secs));
+    }
+}
+
+#[test]
+fn as_millis_ceiling() {
+    assert_eq!(Duration::ZERO.as_millis_ceiling(), 0);
+    assert_eq!(Duration::NANOSECOND.as_millis_ceiling(), 1);
+    assert_eq!(Duration::from_millis(1).as_millis_ceiling(), 1);
+    assert_eq!(Duration::new(1, 1_000_001).as_millis_ceiling(), 1_002);
+    assert_eq!(Duration::new(1, 999_999_999).as_millis_ceiling(), 2_000);
+    assert_eq!(Duration::MAX.as_millis_ceiling(), u64::MAX as u128 * 1_000 + 1_000);
+}
+
+#[test]
+fn step_iter() {
+    let mut iter = Duration::from_secs(1).step_iter(Duration::from_millis(500));
+    assert_eq!(iter.next(), Some(Duration::from_secs(1)));
+    assert_eq!(iter.next(), Some(Duration::from_millis(1_500)));
+    assert_eq!(iter.next(), Some(Duration::from_secs(2)));
+
+    let start = Duration::MAX - Duration::from_nanos(2);
+    let mut iter = start.step_iter(Duration::NANOSECOND);
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+    assert_eq!(iter.next(), Some(start));
+    assert_eq!(iter.next(), Some(Duration::MAX - Duration::NANOSECOND));
+    assert_eq!(iter.next(), Some(Duration::MAX));
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+
+    assert_eq!(Duration::MAX.step_iter(Duration::MAX).count(), 1);
+    assert_eq!(Duration::ZERO.step_iter(Duration::MAX).count(), 2);
+    assert_eq!(Duration::ZERO.step_iter(Duration::from_secs(u64::MAX / 2)).count(), 3);
+
+    let mut iter = Duration::SECOND.step_iter(Duration::ZERO);
+    assert_eq!(iter.size_hint(), (usize::MAX, None));
+    assert_eq!(iter.nth(1_000), Some(Duration::SECOND));
+}
+
+#[test]
+fn clamp_to_range() {
+    let min = Duration::from_millis(100);
+    let max = Duration::from_secs(2);
+    assert_eq!(Duration::ZERO.clamp_to_range(min, max), Some(min));
+    assert_eq!(min.clamp_to_range(min, max), Some(min));
+    assert_eq!(Duration::SECOND.clamp_to_range(min, max), Some(Duration::SECOND));
+    assert_eq!(max.clamp_to_range(min, max), Some(max));
+    assert_eq!(Duration::MAX.clamp_to_range(min, max), Some(max));
+    assert_eq!(Duration::SECOND.clamp_to_range(max, max), Some(max));
+    assert_eq!(Duration::SECOND.clamp_to_range(max, min), None);
+    assert_eq!(Duration::ZERO.clamp_to_range(Duration::NANOSECOND, Duration::ZERO), None);
+}
+
+#[test]
+fn pct() {
+    assert_eq!(Duration::from_secs(10).pct(75, 100), Some(Duration::new(7, 500_000_000)));
+    assert_eq!(Duration::from_secs(10).pct(0, 100), Some(Duration::ZERO));
+    assert_eq!(Duration::ZERO.pct(u64::MAX, 1), Some(Duration::ZERO));
+    assert_eq!(Duration::from_nanos(2).pct(1, 3), Some(Duration::ZERO));
+    assert_eq!(Duration::from_nanos(3).pct(2, 3), Some(Duration::from_nanos(2)));
+    assert_eq!(Duration::from_secs(3).pct(u64::MAX, u64::MAX - 1), Some(Duration::from_secs(3)));
+    let max_secs = Duration::from_secs(u64::MAX);
+    assert_eq!(max_secs.pct(u64::MAX - 1, u64::MAX), Some(Duration::from_secs(u64::MAX - 1)));
+    assert_eq!(Duration::MAX.pct(1, 1), Some(Duration::MAX));
+    assert_eq!(Duration::new(u64::MAX / 2, 0).pct(2, 1), Some(Duration::new(u64::MAX - 1, 0)));
+    assert_eq!(Duration::MAX.pct(u64::MAX, 1), None);
+    assert_eq!(Duration::MAX.pct(3, 2), None);
+    assert_eq!(Duration::MAX.pct(0, 0), None);
+}
+
+#[test]
+fn signed_duration_neg() {
+    let one = SignedDuration::from(Duration::SECOND);
+    let minus_one = -one;
+    assert!(!one.is_negative());
+    assert!(minus_one.is_negative());
+    assert_ne!(one, minus_one);
+    assert_eq!(-minus_one, one);
+    assert_eq!(-SignedDuration::ZERO, SignedDuration::ZERO);
+    assert!(!(-SignedDuration::ZERO).is_negative());
+    assert_eq!(-SignedDuration::from(Duration::ZERO), SignedDuration::ZERO);
+}
+
+#[test]
+fn signed_duration_ord() {
+    let two = SignedDuration::from(Duration::from_secs(2));
+    let one = SignedDuration::from(Duration::SECOND);
+    let mut durations = [one, -two, SignedDuration::ZERO, two, -one];
+    durations.sort();
+    assert_eq!(durations, [-two, -one, SignedDuration::ZERO, one, two]);
+    assert!(-SignedDuration::from(Duration::MAX) < -one);
+}
+
+#[test]
+fn signed_duration_abs_signum() {
+    let one = SignedDuration::from(Duration::SECOND);
+    assert_eq!(one.abs(), Duration::SECOND);
+    assert_eq!((-one).abs(), Duration::SECOND);
+    assert_eq!(SignedDuration::ZERO.abs(), Duration::ZERO);
+    assert_eq!((-SignedDuration::from(Duration::MAX)).abs(), Duration::MAX);
+    assert_eq!(one.signum(), 1);
+    assert_eq!((-one).signum(), -1);
+    assert_eq!(SignedDuration::ZERO.signum(), 0);
+    assert_eq!((-SignedDuration::ZERO).signum(), 0);
+}
+
+#[test]
+fn abs_diff() {
+    assert_eq!(Duration::new(2, 0).abs_diff(Duration::new(1, 0)), Duration::new(1, 0));
+    assert_eq!(Duration::new(1, 0).abs_diff(Duration::new(2, 0)), Duration::new(1, 0));
+    assert_eq!(Duration::new(1, 0).abs_diff(Duration::new(0, 1)), Duration::new(0, 999_999_999));
+    assert_eq!(Duration::new(0, 1).abs_diff(Duration::new(1, 0)), Duration::new(0, 999_999_999));
+    assert_eq!(Duration::new(1, 1).abs_diff(Duration::new(1, 1)), Duration::ZERO);
+    assert_eq!(Duration::ZERO.abs_diff(Duration::MAX), Duration::MAX);
+    assert_eq!(Duration::MAX.abs_diff(Duration::ZERO), Duration::MAX);
+}
+
+#[test]
+fn wrapping_sub_or_negative() {
+    let sub = Duration::wrapping_sub_or_negative;
+    assert_eq!(sub(Duration::new(2, 0), Duration::new(1, 0)), Ok(Duration::new(1, 0)));
+    assert_eq!(sub(Duration::new(1, 0), Duration::new(2, 0)), Err(Duration::new(1, 0)));
+    assert_eq!(sub(Duration::new(1, 0), Duration::new(0, 1)), Ok(Duration::new(0, 999_999_999)));
+    assert_eq!(sub(Duration::new(0, 1), Duration::new(1, 0)), Err(Duration::new(0, 999_999_999)));
+    assert_eq!(sub(Duration::MAX, Duration::MAX), Ok(Duration::ZERO));
+    assert_eq!(sub(Duration::ZERO, Duration::MAX), Err(Duration::MAX));
+    assert_eq!(sub(Duration::MAX, Duration::ZERO), Ok(Duration::MAX));
+}
+
+#[test]
+fn split_at() {
+    let ms = Duration::from_millis;
+    assert_eq!(Duration::ZERO.split_at(Duration::ZERO), (Duration::ZERO, Some(Duration::ZERO)));
+    assert_eq!(Duration::ZERO.split_at(ms(1)), (Duration::ZERO, None));
+    assert_eq!(ms(1).split_at(Duration::ZERO), (Duration::ZERO, Some(ms(1))));
+    assert_eq!(
+        Duration::new(1, 0).split_at(Duration::new(0, 1)),
+        (Duration::new(0, 1), Some(Duration::new(0, 999_999_999)))
+    );
+    assert_eq!(Duration::new(0, 1).split_at(Duration::new(1, 0)), (Duration::new(0, 1), None));
+    assert_eq!(Duration::MAX.split_at(Duration::MAX), (Duration::MAX, Some(Duration::ZERO)));
+    assert_eq!(Duration::MAX.split_at(ms(1)), (ms(1), Some(Duration::MAX - ms(1))));
+}
+
+#[test]
+fn approx_cmp() {
+    let a = Duration::from_millis(100);
+    let b = Duration::from_millis(110);
+    let tolerance = Duration::from_millis(10);
+    assert!(!a.approx_lt(b, tolerance));
+    assert!(a.approx_lt(b, Duration::from_millis(9)));
+    assert!(a.approx_le(b, tolerance));
+    assert!(b.approx_le(a, tolerance));
+    assert!(!b.approx_le(a, Duration::from_millis(9)));
+    assert!(!b.approx_gt(a, tolerance));
+    assert!(b.approx_gt(a, Duration::from_millis(9)));
+    assert!(a.approx_ge(b, tolerance));
+    assert!(!a.approx_ge(b, Duration::from_millis(9)));
+
+    assert!(a.approx_le(a, Duration::ZERO));
+    assert!(a.approx_ge(a, Duration::ZERO));
+    assert!(!a.approx_lt(a, Duration::ZERO));
+    assert!(!a.approx_gt(a, Duration::ZERO));
+
+    assert!(!Duration::MAX.approx_lt(Duration::MAX, Duration::MAX));
+    assert!(Duration::MAX.approx_le(Duration::ZERO, Duration::MAX));
+    assert!(!Duration::MAX.approx_gt(Duration::ZERO, Duration::MAX));
+    assert!(Duration::ZERO.approx_ge(Duration::MAX, Duration::MAX));
+}
+
+/// Asserts that `a` and `b` differ by at most `tolerance`.
+#[track_caller]
+fn assert_approx_eq(a: Duration, b: Duration, tolerance: Duration) {
+    let difference = a.abs_diff(b);
+    assert!(
+        difference <= tolerance,
+        "durations differ by {difference:?} (tolerance: {tolerance:?})"
+    );
+}
+
+#[test]
+fn compare_with_tolerance() {
+    use core::cmp::Ordering::{Equal, Greater, Less};
+
+    let ms = Duration::from_millis;
+    assert_eq!(ms(100).compare_with_tolerance(ms(100), Duration::ZERO), Equal);
+    assert_eq!(ms(100).compare_with_tolerance(ms(101), Duration::ZERO), Less);
+    assert_eq!(ms(101).compare_with_tolerance(ms(100), Duration::ZERO), Greater);
+    assert_eq!(ms(90).compare_with_tolerance(ms(100), ms(10)), Equal);
+    assert_eq!(ms(89).compare_with_tolerance(ms(100), ms(10)), Less);
+    assert_eq!(ms(110).compare_with_tolerance(ms(100), ms(10)), Equal);
+    assert_eq!(ms(111).compare_with_tolerance(ms(100), ms(10)), Greater);
+    assert_eq!(Duration::ZERO.compare_with_tolerance(Duration::MAX, Duration::MAX), Equal);
+    assert_eq!(Duration::MAX.compare_with_tolerance(Duration::ZERO, ms(1)), Greater);
+}
+
+#[test]
+fn approx_eq() {
+    let second = Duration::SECOND;
+    assert_approx_eq(second, second, Duration::ZERO);
+    assert_approx_eq(second, Duration::from_millis(999), Duration::from_millis(1));
+    assert_approx_eq(Duration::from_millis(999), second, Duration::from_millis(1));
+    assert_approx_eq(Duration::ZERO, Duration::MAX, Duration::MAX);
+}
+
+#[test]
+#[should_panic(expected = "durations differ by 2ms (tolerance: 1ms)")]
+fn approx_eq_fails() {
+    assert_approx_eq(Duration::from_millis(1_002), Duration::SECOND, Duration::from_millis(1));
+}
+
+#[test]
+fn scale_to_fit() {
+    let ms = Duration::from_millis;
+    assert_eq!(ms(250).scale_to_fit(ms(1_000)), (ms(1_000), 4));
+    assert_eq!(ms(250).scale_to_fit(ms(999)), (ms(750), 3));
+    assert_eq!(ms(250).scale_to_fit(ms(249)), (Duration::ZERO, 0));
+    assert_eq!(ms(250).scale_to_fit(Duration::ZERO), (Duration::ZERO, 0));
+    assert_eq!(Duration::ZERO.scale_to_fit(Duration::ZERO), (Duration::ZERO, 0));
+    assert_eq!(Duration::ZERO.scale_to_fit(Duration::MAX), (Duration::ZERO, 0));
+    assert_eq!(Duration::MAX.scale_to_fit(Duration::MAX), (Duration::MAX, 1));
+    assert_eq!(
+        Duration::SECOND.scale_to_fit(Duration::MAX),
+        (Duration::new(u64::MAX, 0), u64::MAX as u128)
+    );
+    assert_eq!(
+        Duration::NANOSECOND.scale_to_fit(Duration::MAX),
+        (Duration::MAX, Duration::MAX.as_nanos())
+    );
+}
+
+#[test]
+fn ratio_u128() {
+    assert_eq!(Duration::SECOND.ratio_u128(Duration::SECOND), (1, 1));
+    assert_eq!(Duration::from_millis(1).ratio_u128(Duration::SECOND), (1, 1_000));
+    assert_eq!(Duration::from_secs(6).ratio_u128(Duration::from_secs(4)), (3, 2));
+    assert_eq!(Duration::from_nanos(7).ratio_u128(Duration::from_nanos(13)), (7, 13));
+    assert_eq!(Duration::ZERO.ratio_u128(Duration::MAX), (0, 1));
+    assert_eq!(Duration::MAX.ratio_u128(Duration::NANOSECOND), (Duration::MAX.as_nanos(), 1));
+    assert_eq!(Duration::MAX.ratio_u128(Duration::MAX), (1, 1));
+}
+
+#[test]
+#[should_panic]
+fn ratio_u128_zero() {
+    let _ = Duration::SECOND.ratio_u128(Duration::ZERO);
+}
+
+#[test]
+fn gcd() {
+    let ms = Duration::from_millis;
+    assert_eq!(ms(100).gcd(ms(150)), ms(50));
+    assert_eq!(ms(150).gcd(ms(100)), ms(50));
+    assert_eq!(ms(100).gcd(ms(100)), ms(100));
+    assert_eq!(Duration::from_secs(3).gcd(Duration::new(1, 1)), Duration::NANOSECOND);
+    assert_eq!(Duration::ZERO.gcd(ms(7)), ms(7));
+    assert_eq!(ms(7).gcd(Duration::ZERO), ms(7));
+    assert_eq!(Duration::ZERO.gcd(Duration::ZERO), Duration::ZERO);
+    assert_eq!(Duration::MAX.gcd(Duration::MAX), Duration::MAX);
+    assert_eq!(Duration::MAX.gcd(Duration::ZERO), Duration::MAX);
+}
+
+#[test]
+fn lcm() {
+    let ms = Duration::from_millis;
+    assert_eq!(ms(100).lcm(ms(150)), Some(ms(300)));
+    assert_eq!(ms(150).lcm(ms(100)), Some(ms(300)));
+    assert_eq!(ms(100).lcm(ms(100)), Some(ms(100)));
+    assert_eq!(ms(100).lcm(ms(50)), Some(ms(100)));
+    assert_eq!(Duration::ZERO.lcm(ms(7)), Some(Duration::ZERO));
+    assert_eq!(Duration::ZERO.lcm(Duration::ZERO), Some(Duration::ZERO));
+    assert_eq!(Duration::MAX.lcm(Duration::NANOSECOND), Some(Duration::MAX));
+    assert_eq!(Duration::MAX.lcm(Duration::MAX), Some(Duration::MAX));
+    assert_eq!(
+        Duration::from_secs(u64::MAX / 2).lcm(Duration::from_secs(2)),
+        Some(Duration::from_secs(u64::MAX - 1))
+    );
+    assert_eq!(Duration::from_secs(u64::MAX).lcm(Duration::from_secs(2)), None);
+    // The product of the nanoseconds overflows `u128`.
+    assert_eq!(Duration::MAX.lcm(Duration::MAX - Duration::NANOSECOND), None);
+}
+
+#[test]
+fn spread() {
+    assert_eq!(Duration::spread(1, Duration::MAX), Some(Duration::MAX));
+    assert_eq!(Duration::spread(2, Duration::from_secs(3)), Some(Duration::from_millis(1_500)));
+    assert_eq!(Duration::spread(3, Duration::from_nanos(2)), Some(Duration::ZERO));
+    assert_eq!(Duration::spread(u32::MAX, Duration::ZERO), Some(Duration::ZERO));
+    assert_eq!(Duration::spread(0, Duration::ZERO), None);
+}
+
+/// Returns a random `Duration`, biased towards the edge cases of the
+/// arithmetic: zero and maximal seconds, and nanoseconds next to a carry.
+fn random_duration(rng: &mut impl rand::Rng) -> Duration {
+    let secs = match rng.gen_range(0..6) {
+        0 => 0,
+        1 => u64::MAX,
+        2 => u64::MAX - rng.gen_range(0..1_000u64),
+        3 => rng.gen_range(0..1_000),
+        4 => rng.gen_range(0..u32::MAX as u64),
+        _ => rng.gen(),
+    };
+    let nanos = match rng.gen_range(0..4) {
+        0 => 0,
+        1 => 999_999_999 - rng.gen_range(0..10u32),
+        2 => rng.gen_range(0..10),
+        _ => rng.gen_range(0..1_000_000_000),
+    };
+    Duration::new(secs, nanos)
+}
+
+/// Parses the default `Debug` output of a `Duration` back into a `Duration`.
+fn parse_debug(s: &str) -> Duration {
+    let (number, unit_nanos) = if let Some(number) = s.strip_suffix("ns") {
+        (number, 1)
+    } else if let Some(number) = s.strip_suffix("µs") {
+        (number, 1_000)
+    } else if let Some(number) = s.strip_suffix("ms") {
+        (number, 1_000_000)
+    } else {
+        (s.strip_suffix('s').unwrap(), 1_000_000_000)
+    };
+    let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+    let mut nanos = integer.parse::<u128>().unwrap() * unit_nanos;
+    if !fraction.is_empty() {
+        let scale = 10u128.pow(fraction.len() as u32);
+        nanos += fraction.parse::<u128>().unwrap() * unit_nanos / scale;
+    }
+    Duration::new((nanos / 1_000_000_000) as u64, (nanos % 1_000_000_000) as u32)
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // Miri is too slow
+fn random_arithmetic_properties() {
+    use rand::Rng;
+
+    let mut rng = crate::test_rng();
+    for _ in 0..10_000 {
+        let a = random_duration(&mut rng);
+        let b = random_duration(&mut rng);
+        let c = random_duration(&mut rng);
+        let k = match rng.gen_range(0..3) {
+            0 => rng.gen_range(1..10u32),
+            1 => u32::MAX - rng.gen_range(0..10u32),
+            _ => rng.gen_range(1..=u32::MAX),
+        };
+
+        assert_eq!(a.checked_add(b), b.checked_add(a), "{a:?} + {b:?}");
+        if let (Some(ab), Some(bc)) = (a.checked_add(b), b.checked_add(c)) {
+            assert_eq!(ab.checked_add(c), a.checked_add(bc), "{a:?} + {b:?} + {c:?}");
+        }
+        match a.checked_add(b) {
+            Some(sum) => {
+                assert_eq!(sum - b, a, "{a:?} + {b:?} - {b:?}");
+                assert_eq!(sum - a, b, "{a:?} + {b:?} - {a:?}");
+            }
+            None => assert_eq!(a.saturating_add(b), Duration::MAX),
+        }
+        match a.checked_sub(b) {
+            Some(difference) => assert_eq!(difference + b, a, "{a:?} - {b:?} + {b:?}"),
+            None => assert!(a < b),
+        }
+        if let Some(product) = a.checked_mul(k) {
+            assert_eq!(product / k, a, "{a:?} * {k} / {k}");
+        }
+        let quotient = a / k;
+        assert!(quotient * k <= a, "{a:?} / {k} * {k}");
+        assert!(a - quotient * k < Duration::from_nanos(k as u64), "{a:?} / {k}");
+    }
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // Miri is too slow
+fn random_debug_round_trip() {
+    let mut rng = crate::test_rng();
+    for _ in 0..10_000 {
+        let mut d = random_duration(&mut rng);
+        // Also cover the output in milliseconds, microseconds and nanoseconds.
+        d /= 10u32.pow(rand::Rng::gen_range(&mut rng, 0..10));
+        let s = format!("{d:?}");
+        assert_eq!(parse_debug(&s), d, "{s}");
+    }
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // Miri is too slow
+fn random_mul_div_f64_round_trip() {
+    use rand::Rng;
+
+    let mut rng = crate::test_rng();
+    for _ in 0..10_000 {
+        let secs = match rng.gen_range(0..3) {
+            0 => rng.gen_range(0..1_000u64),
+            1 => rng.gen_range(0..u32::MAX as u64),
+            // Leave room for multiplying by up to 1000.
+            _ => rng.gen_range(0..u64::MAX >> 11),
+        };
+        let d = Duration::new(secs, rng.gen_range(0..1_000_000_000));
+        let s = rng.gen_range(1.0..1_000.0);
+
+        let back = d.mul_f64(s).div_f64(s);
+        let error = if back > d { back - d } else { d - back };
+        // Each conversion to and from `f64` may round by a few units in the last
+        // place, and the conversions back to `Duration` truncate to nanoseconds.
+        let tolerance = d.mul_f64(4.0 * f64::EPSILON) + Duration::from_nanos(1);
+        assert!(error <= tolerance, "{d:?} * {s} / {s} = {back:?}");
+
+        let back = d.mul_f32(s as f32).div_f32(s as f32);
+        let error = if back > d { back - d } else { d - back };
+        let tolerance = d.mul_f32(4.0 * f32::EPSILON) + Duration::from_nanos(1);
+        assert!(error <= tolerance, "{d:?} * {s} / {s} = {back:?}");
+    }
+}
+
+#[test]
+fn from_be_bytes_padded() {
+    let bytes = [1, 2, 3, 4, 5, 6, 7, 8, 0x3b, 0x9a, 0xc9, 0xff];
+    let expected = Duration::new(0x0102_0304_0506_0708, 999_999_999);
+    assert_eq!(Duration::from_be_bytes_padded([0; 16]), Some(Duration::ZERO));
+
+    let mut padded = [0; 16];
+    padded[..12].copy_from_slice(&bytes);
+    assert_eq!(Duration::from_be_bytes_padded(padded), Some(expected));
+    for i in 12..16 {
+        let mut dirty = padded;
+        dirty[i] = 0x80;
+        assert_eq!(Duration::from_be_bytes_padded(dirty), None);
+    }
+    padded[11] = 0;
+    padded[8] = 0xff;
+    assert_eq!(Duration::from_be_bytes_padded(padded), None);
+}
+
+#[test]
+fn be_bytes() {
+    let duration = Duration::new(0x0102_0304_0506_0708, 999_999_999);
+    let bytes = [1, 2, 3, 4, 5, 6, 7, 8, 0x3b, 0x9a, 0xc9, 0xff];
+    assert_eq!(duration.to_be_bytes(), bytes);
+    assert_eq!(Duration::from_be_bytes(bytes), Some(duration));
+    assert_eq!(Duration::ZERO.to_be_bytes(), [0; 12]);
+    assert_eq!(Duration::from_be_bytes(Duration::MAX.to_be_bytes()), Some(Duration::MAX));
+    assert_eq!(Duration::from_be_bytes([0, 0, 0, 0, 0, 0, 0, 0, 0x3b, 0x9a, 0xca, 0]), None);
+}
+
+#[test]
+fn is_multiple_of() {
+    let ms = Duration::from_millis;
+    assert!(ms(300).is_multiple_of(ms(100)));
+    assert!(ms(300).is_multiple_of(ms(300)));
+    assert!(!ms(300).is_multiple_of(ms(200)));
+    assert!(!ms(100).is_multiple_of(ms(300)));
+    assert!(!Duration::new(1, 1).is_multiple_of(Duration::SECOND));
+    assert!(Duration::ZERO.is_multiple_of(Duration::MAX));
+    assert!(Duration::MAX.is_multiple_of(Duration::NANOSECOND));
+    assert!(Duration::MAX.is_multiple_of(Duration::MAX));
+}
+
+#[test]
+#[should_panic]
+fn is_multiple_of_zero() {
+    let _ = Duration::SECOND.is_multiple_of(Duration::ZERO);
+}
+
+#[test]
+fn round_to_nearest_multiple() {
+    let ms = Duration::from_millis;
+    assert_eq!(Duration::ZERO.round_to_nearest_multiple(ms(100)), Duration::ZERO);
+    assert_eq!(ms(49).round_to_nearest_multiple(ms(100)), Duration::ZERO);
+    assert_eq!(ms(50).round_to_nearest_multiple(ms(100)), ms(100));
+    assert_eq!(ms(149).round_to_nearest_multiple(ms(100)), ms(100));
+    assert_eq!(ms(200).round_to_nearest_multiple(ms(100)), ms(200));
+    assert_eq!(ms(100).round_to_nearest_multiple(ms(300)), Duration::ZERO);
+    assert_eq!(ms(150).round_to_nearest_multiple(ms(300)), ms(300));
+    assert_eq!(
+        Duration::from_nanos(2).round_to_nearest_multiple(Duration::from_nanos(3)),
+        Duration::from_nanos(3)
+    );
+    assert_eq!(
+        Duration::from_nanos(4).round_to_nearest_multiple(Duration::from_nanos(3)),
+        Duration::from_nanos(3)
+    );
+    assert_eq!(Duration::MAX.round_to_nearest_multiple(Duration::NANOSECOND), Duration::MAX);
+    assert_eq!(Duration::MAX.round_to_nearest_multiple(Duration::MAX), Duration::MAX);
+    assert_eq!((Duration::MAX / 2).round_to_nearest_multiple(Duration::MAX), Duration::ZERO);
+    assert_eq!(
+        Duration::new(u64::MAX, 0).round_to_nearest_multiple(Duration::SECOND),
+        Duration::new(u64::MAX, 0)
+    );
+}
+
+#[test]
+#[should_panic]
+fn round_to_nearest_multiple_zero() {
+    let _ = Duration::SECOND.round_to_nearest_multiple(Duration::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn round_to_nearest_multiple_overflow() {
+    let _ = Duration::MAX.round_to_nearest_multiple(Duration::SECOND);
+}
+
+#[test]
+fn reciprocal_nanos() {
+    assert_eq!(Duration::NANOSECOND.reciprocal_nanos(), 1.0);
+    assert_eq!(Duration::from_nanos(2).reciprocal_nanos(), 0.5);
+    assert_eq!(Duration::SECOND.reciprocal_nanos(), 1e-9);
+    assert_eq!(Duration::ZERO.reciprocal_nanos(), f64::INFINITY);
+    assert!(Duration::MAX.reciprocal_nanos() > 0.0);
+}
+
+#[test]
+fn into_total_f64() {
+    assert_eq!(Duration::ZERO.into_total_millis_f64(), 0.0);
+    assert_eq!(Duration::new(1, 500_000).into_total_millis_f64(), 1_000.5);
+    assert_eq!(Duration::NANOSECOND.into_total_millis_f64(), 1e-6);
+    assert_eq!(Duration::MAX.into_total_millis_f64(), u64::MAX as f64 * 1e3);
+
+    assert_eq!(Duration::ZERO.into_total_micros_f64(), 0.0);
+    assert_eq!(Duration::new(1, 500).into_total_micros_f64(), 1_000_000.5);
+    assert_eq!(Duration::NANOSECOND.into_total_micros_f64(), 1e-3);
+    assert_eq!(Duration::MAX.into_total_micros_f64(), u64::MAX as f64 * 1e6);
+}
+
+#[test]
+fn as_millis_micros_f64() {
+    assert_eq!(Duration::ZERO.as_millis_f64(), 0.0);
+    assert_eq!(Duration::new(1, 500_000).as_millis_f64(), 1_000.5);
+    assert_eq!(Duration::NANOSECOND.as_millis_f64(), 1e-6);
+    assert_eq!(Duration::MAX.as_millis_f64(), Duration::MAX.into_total_millis_f64());
+
+    assert_eq!(Duration::ZERO.as_micros_f64(), 0.0);
+    assert_eq!(Duration::new(1, 500).as_micros_f64(), 1_000_000.5);
+    assert_eq!(Duration::NANOSECOND.as_micros_f64(), 1e-3);
+    assert_eq!(Duration::MAX.as_micros_f64(), Duration::MAX.into_total_micros_f64());
+
+    // Whole milliseconds and microseconds are exact up to 2^53 of them.
+    let max_exact = 1 << 53;
+    assert_eq!(Duration::from_millis(max_exact).as_millis_f64(), max_exact as f64);
+    assert_ne!(Duration::from_millis(max_exact + 1).as_millis_f64() as u64, max_exact + 1);
+    assert_eq!(Duration::from_micros(max_exact).as_micros_f64(), max_exact as f64);
+    assert_ne!(Duration::from_micros(max_exact + 1).as_micros_f64() as u64, max_exact + 1);
+}
+
+#[test]
+fn as_f64_large_units() {
+    assert_eq!(Duration::ZERO.as_f64_mins(), 0.0);
+    assert_eq!(Duration::from_secs(60).as_f64_mins(), 1.0);
+    assert_eq!(Duration::from_millis(1_500).as_f64_mins(), 0.025);
+    assert_eq!(Duration::SECOND.as_f64_mins(), 1.0 / 60.0);
+
+    assert_eq!(Duration::ZERO.as_f64_hours(), 0.0);
+    assert_eq!(Duration::from_secs(3_600).as_f64_hours(), 1.0);
+    assert_eq!(Duration::from_secs(900).as_f64_hours(), 0.25);
+
+    assert_eq!(Duration::ZERO.as_f64_days(), 0.0);
+    assert_eq!(Duration::from_secs(86_400 * 7).as_f64_days(), 7.0);
+    assert_eq!(Duration::from_secs(21_600).as_f64_days(), 0.25);
+    assert_eq!(Duration::MAX.as_f64_days(), Duration::MAX.as_secs_f64() / 86_400.0);
+}
+
+#[test]
+fn subsec_nanos_as_float() {
+    assert_eq!(Duration::ZERO.subsec_nanos_as_f64(), 0.0);
+    assert_eq!(Duration::new(5, 0).subsec_nanos_as_f64(), 0.0);
+    assert_eq!(Duration::new(5, 500_000_000).subsec_nanos_as_f64(), 0.5);
+    assert_eq!(Duration::NANOSECOND.subsec_nanos_as_f64(), 1e-9);
+    assert_eq!(Duration::MAX.subsec_nanos_as_f64(), 0.999_999_999);
+
+    assert_eq!(Duration::ZERO.subsec_nanos_as_f32(), 0.0);
+    assert_eq!(Duration::new(5, 500_000_000).subsec_nanos_as_f32(), 0.5);
+    assert_eq!(Duration::NANOSECOND.subsec_nanos_as_f32(), 1e-9);
+    assert_eq!(Duration::MAX.subsec_nanos_as_f32(), 1.0 - f32::EPSILON / 2.0);
+    assert!(Duration::new(0, 999_999_970).subsec_nanos_as_f32() < 1.0);
+}
+
+#[test]
+fn checked_from_nanos_f64() {
+    assert_eq!(Duration::checked_from_nanos_f64(0.0), Some(Duration::ZERO));
+    assert_eq!(Duration::checked_from_nanos_f64(-0.0), Some(Duration::ZERO));
+    assert_eq!(Duration::checked_from_nanos_f64(1.0), Some(Duration::NANOSECOND));
+    assert_eq!(Duration::checked_from_nanos_f64(1_000_000_123.0), Some(Duration::new(1, 123)));
+    assert_eq!(Duration::checked_from_nanos_f64(1e18), Some(Duration::from_secs(1_000_000_000)));
+    assert_eq!(Duration::checked_from_nanos_f64(-1e-9), None);
+    assert_eq!(Duration::checked_from_nanos_f64(f64::NEG_INFINITY), None);
+    assert_eq!(Duration::checked_from_nanos_f64(f64::MAX), None);
+    assert_eq!(Duration::checked_from_nanos_f64(u64::MAX as f64 * 1e9 * 2.0), None);
+}
+
+#[test]
+fn javascript_ms() {
+    assert_eq!(Duration::from_javascript_ms(0.0), Some(Duration::ZERO));
+    assert_eq!(Duration::from_javascript_ms(-0.0), Some(Duration::ZERO));
+    assert_eq!(Duration::from_javascript_ms(1.25), Some(Duration::from_micros(1_250)));
+    assert_eq!(Duration::from_javascript_ms(0.000_000_4), Some(Duration::ZERO));
+    assert_eq!(Duration::from_javascript_ms(0.000_001_6), Some(Duration::from_nanos(2)));
+    assert_eq!(Duration::from_javascript_ms(0.999_999_9), Some(Duration::from_millis(1)));
+    // The whole milliseconds are exact even where an `f64` has no nanosecond precision.
+    let ms = (1u64 << 60) as f64;
+    assert_eq!(Duration::from_javascript_ms(ms), Some(Duration::from_millis(1 << 60)));
+    let now = 1_700_000_000_123.5;
+    assert_eq!(Duration::from_javascript_ms(now), Some(Duration::new(1_700_000_000, 123_500_000)));
+
+    assert_eq!(Duration::from_javascript_ms(-1e-9), None);
+    assert_eq!(Duration::from_javascript_ms(f64::NAN), None);
+    assert_eq!(Duration::from_javascript_ms(f64::INFINITY), None);
+    assert_eq!(Duration::from_javascript_ms(f64::MAX), None);
+    assert_eq!(Duration::from_javascript_ms(u64::MAX as f64 * 1e3), None);
+
+    for d in [Duration::ZERO, Duration::from_micros(1_500), Duration::new(86_400, 250_000_000)] {
+        assert_eq!(Duration::from_javascript_ms(d.to_javascript_ms()), Some(d));
+    }
+    assert_eq!(Duration::ZERO.to_javascript_ms(), 0.0);
+    assert_eq!(Duration::from_nanos(1_250_000).to_javascript_ms(), 1.25);
+}
+
+#[test]
+fn timeout() {
+    assert_eq!(Duration::MAX.timeout(), None);
+    assert_eq!(
+        (Duration::MAX - Duration::NANOSECOND).timeout(),
+        Some(Duration::MAX - Duration::NANOSECOND)
+    );
+    assert_eq!(Duration::new(u64::MAX, 0).timeout(), Some(Duration::new(u64::MAX, 0)));
+    assert_eq!(Duration::ZERO.timeout(), Some(Duration::ZERO));
+
+    assert_eq!(Duration::from_timeout(None), Duration::MAX);
+    assert_eq!(Duration::from_timeout(Some(Duration::ZERO)), Duration::ZERO);
+    for duration in [Duration::ZERO, Duration::SECOND, Duration::MAX] {
+        assert_eq!(Duration::from_timeout(duration.timeout()), duration);
+    }
+}
+
+#[test]
+fn approx_div() {
+    let dur = Duration::new(2, 700_000_000);
+    assert_eq!(dur.approx_div(1.0), Some(dur));
+    assert_eq!(dur.approx_div(2.0), Some(dur.div_f64(2.0)));
+    assert_eq!(dur.approx_div(f64::INFINITY), Some(Duration::ZERO));
+    assert_eq!(Duration::ZERO.approx_div(3.0), Some(Duration::ZERO));
+    assert_eq!(dur.approx_div(0.0), None);
+    assert_eq!(dur.approx_div(-0.0), None);
+    assert_eq!(Duration::ZERO.approx_div(0.0), None);
+    assert_eq!(dur.approx_div(f64::NAN), None);
+    assert_eq!(dur.approx_div(-2.0), None);
+    assert_eq!(dur.approx_div(f64::MIN_POSITIVE), None);
+    assert_eq!(Duration::MAX.approx_div(0.5), None);
+}
+
+#[test]
+fn approx_mul_f64_safe() {
+    let dur = Duration::new(2, 700_000_000);
+    assert_eq!(dur.approx_mul_f64_safe(1.0), dur);
+    assert_eq!(dur.approx_mul_f64_safe(2.0), dur.mul_f64(2.0));
+    assert_eq!(dur.approx_mul_f64_safe(0.0), Duration::ZERO);
+    assert_eq!(dur.approx_mul_f64_safe(-0.0), Duration::ZERO);
+    assert_eq!(dur.approx_mul_f64_safe(-2.0), Duration::ZERO);
+    assert_eq!(dur.approx_mul_f64_safe(f64::NEG_INFINITY), Duration::ZERO);
+    assert_eq!(dur.approx_mul_f64_safe(f64::INFINITY), Duration::MAX);
+    assert_eq!(dur.approx_mul_f64_safe(f64::MAX), Duration::MAX);
+    assert_eq!(Duration::MAX.approx_mul_f64_safe(1.5), Duration::MAX);
+    assert_eq!(dur.approx_mul_f64_safe(f64::NAN), dur);
+    assert_eq!(Duration::ZERO.approx_mul_f64_safe(f64::NAN), Duration::ZERO);
+    assert_eq!(Duration::ZERO.approx_mul_f64_safe(f64::INFINITY), Duration::ZERO);
+    assert_eq!(Duration::ZERO.approx_mul_f64_safe(f64::MAX), Duration::ZERO);
+}
+
+#[test]
+fn extend_shrink_by_percentage() {
+    let dur = Duration::from_secs(40);
+    assert_eq!(dur.extend_by_percentage(0.0), Some(dur));
+    assert_eq!(dur.extend_by_percentage(50.0), Some(Duration::from_secs(60)));
+    assert_eq!(dur.extend_by_percentage(250.0), Some(Duration::from_secs(140)));
+    assert_eq!(Duration::ZERO.extend_by_percentage(1e300), Some(Duration::ZERO));
+    assert_eq!(dur.extend_by_percentage(-0.5), None);
+    assert_eq!(dur.extend_by_percentage(f64::INFINITY), None);
+    assert_eq!(dur.extend_by_percentage(f64::NAN), None);
+    assert_eq!(Duration::MAX.extend_by_percentage(1.0), None);
+
+    assert_eq!(dur.shrink_by_percentage(0.0), Some(dur));
+    assert_eq!(dur.shrink_by_percentage(50.0), Some(Duration::from_secs(20)));
+    assert_eq!(dur.shrink_by_percentage(100.0), Some(Duration::ZERO));
+    assert_eq!(dur.shrink_by_percentage(100.5), None);
+    assert_eq!(dur.shrink_by_percentage(-0.5), None);
+    assert_eq!(dur.shrink_by_percentage(f64::INFINITY), None);
+    assert_eq!(dur.shrink_by_percentage(f64::NAN), None);
+}
+
+#[test]
+fn strict_ordering() {
+    let durations = [
+        Duration::ZERO,
+        Duration::NANOSECOND,
+        Duration::new(0, 999_999_999),
+        Duration::SECOND,
+        Duration::new(1, 1),
+        Duration::new(2, 0),
+        Duration::new(u64::MAX, 0),
+        Duration::MAX,
+    ];
+    for a in durations {
+        for b in durations {
+            assert_eq!(a.strict_ordering(&b), a.cmp(&b), "{a:?} <=> {b:?}");
+        }
+    }
+}
+
+#[test]
+fn const_min_max() {
+    let durations =
+        [Duration::ZERO, Duration::new(0, 999_999_999), Duration::new(1, 0), Duration::MAX];
+    for a in durations {
+        for b in durations {
+            assert_eq!(Duration::const_min(a, b), a.min(b), "min({a:?}, {b:?})");
+            assert_eq!(Duration::const_max(a, b), a.max(b), "max({a:?}, {b:?})");
+        }
+    }
+
+    const MIN: Duration = Duration::const_min(Duration::SECOND, Duration::MAX);
+    const MAX: Duration = Duration::const_max(Duration::SECOND, Duration::MAX);
+    assert_eq!(MIN, Duration::SECOND);
+    assert_eq!(MAX, Duration::MAX);
+}
+
+#[test]
+fn total_nanos_checked_u64() {
+    assert_eq!(Duration::ZERO.total_nanos_checked_u64(), Some(0));
+    assert_eq!(Duration::new(1, 1).total_nanos_checked_u64(), Some(1_000_000_001));
+    let max = Duration::from_nanos(u64::MAX);
+    assert_eq!(max.total_nanos_checked_u64(), Some(u64::MAX));
+    assert_eq!((max + Duration::NANOSECOND).total_nanos_checked_u64(), None);
+    assert_eq!(Duration::MAX.total_nanos_checked_u64(), None);
+}
+
+#[test]
+fn total_nanos_checked_i64() {
+    assert_eq!(Duration::ZERO.total_nanos_checked_i64(), Some(0));
+    assert_eq!(Duration::new(1, 1).total_nanos_checked_i64(), Some(1_000_000_001));
+    let max = Duration::from_nanos(i64::MAX as u64);
+    assert_eq!(max.total_nanos_checked_i64(), Some(i64::MAX));
+    assert_eq!((max + Duration::NANOSECOND).total_nanos_checked_i64(), None);
+    assert_eq!(Duration::from_nanos(u64::MAX).total_nanos_checked_i64(), None);
+    assert_eq!(Duration::MAX.total_nanos_checked_i64(), None);
+}
+
+#[test]
+fn go_duration() {
+    assert_eq!(Duration::from_go_duration(0), Some(Duration::ZERO));
+    assert_eq!(Duration::from_go_duration(1_000_000_001), Some(Duration::new(1, 1)));
+    assert_eq!(Duration::from_go_duration(i64::MAX), Some(Duration::from_nanos(i64::MAX as u64)));
+    assert_eq!(Duration::from_go_duration(-1), None);
+    assert_eq!(Duration::from_go_duration(i64::MIN), None);
+    for nanos in [0, 1, 999_999_999, 1_000_000_000, i64::MAX] {
+        let d = Duration::from_go_duration(nanos).unwrap();
+        assert_eq!(d.to_go_duration(), Some(nanos));
+    }
+    assert_eq!(Duration::from_nanos(i64::MAX as u64 + 1).to_go_duration(), None);
+    assert_eq!(Duration::MAX.to_go_duration(), None);
+}
+
+#[test]
+fn python_timedelta() {
+    assert_eq!(Duration::from_python_timedelta(0, 0, 0), Some(Duration::ZERO));
+    assert_eq!(
+        Duration::from_python_timedelta(0, 86_399, 999_999),
+        Some(Duration::new(86_399, 999_999_000))
+    );
+    assert_eq!(
+        Duration::from_python_timedelta(0, 86_400, 1_000_000),
+        Some(Duration::from_secs(86_401))
+    );
+    assert_eq!(
+        Duration::from_python_timedelta(i32::MAX, i32::MAX, i32::MAX),
+        Some(Duration::new(i32::MAX as u64 * 86_401 + i32::MAX as u64 / 1_000_000, 483_647_000))
+    );
+    assert_eq!(Duration::from_python_timedelta(-1, 0, 0), None);
+    assert_eq!(Duration::from_python_timedelta(0, -1, 0), None);
+    assert_eq!(Duration::from_python_timedelta(0, 0, -1), None);
+
+    assert_eq!(Duration::ZERO.to_python_timedelta(), (0, 0, 0));
+    assert_eq!(Duration::new(86_399, 999_999_999).to_python_timedelta(), (0, 86_399, 999_999));
+    assert_eq!(Duration::from_secs(86_400).to_python_timedelta(), (1, 0, 0));
+    assert_eq!(Duration::MAX.to_python_timedelta(), ((u64::MAX / 86_400) as i64, 25_215, 999_999));
+
+    for (days, seconds, microseconds) in [(0, 0, 1), (1, 2, 3), (999_999_999, 86_399, 999_999)] {
+        let d = Duration::from_python_timedelta(days, seconds, microseconds).unwrap();
+        assert_eq!(d.to_python_timedelta(), (days as i64, seconds, microseconds));
+    }
+}
@@ -282,6 +282,11 @@
 #![feature(cstr_from_bytes_until_nul)]
 #![feature(cstr_internals)]
 #![feature(duration_constants)]
+#![feature(duration_parse)]
+#![feature(duration_range)]
+#![feature(duration_step_iter)]
+#![feature(duration_try_sub)]
+#![feature(signed_duration)]
 #![feature(error_generic_member_access)]
 #![feature(error_in_core)]
 #![feature(error_iter)]
@@ -46,6 +46,21 @@
 #[stable(feature = "duration_checked_float", since = "1.66.0")]
 pub use core::time::TryFromFloatSecsError;
 
+#[unstable(feature = "duration_parse", issue = "none")]
+pub use core::time::ParseDurationError;
+
+#[unstable(feature = "duration_range", issue = "none")]
+pub use core::time::DurationRange;
+
+#[unstable(feature = "duration_step_iter", issue = "none")]
+pub use core::time::StepIter;
+
+#[unstable(feature = "signed_duration", issue = "none")]
+pub use core::time::SignedDuration;
+
+#[unstable(feature = "duration_try_sub", issue = "none")]
+pub use core::time::DurationUnderflowError;
+
 /// A measurement of a monotonically nondecreasing clock.
 /// Opaque and useful only with [`Duration`].
 ///
@@ -297,7 +312,7 @@ pub fn now() -> Instant {
     /// sleep(Duration::new(1, 0));
     /// let new_now = Instant::now();
     /// println!("{:?}", new_now.duration_since(now));
-    /// println!("{:?}", now.duration_since(new_now)); // 0ns
+    /// println!("{:?}", now.duration_since(new_now)); // 0s
     /// ```
     #[must_use]
     #[stable(feature = "time2", since = "1.8.0")]
@@ -344,7 +359,7 @@ pub fn checked_duration_since(&self, earlier: Instant) -> Option<Duration> {
     /// sleep(Duration::new(1, 0));
     /// let new_now = Instant::now();
     /// println!("{:?}", new_now.saturating_duration_since(now));
-    /// println!("{:?}", now.saturating_duration_since(new_now)); // 0ns
+    /// println!("{:?}", now.saturating_duration_since(new_now)); // 0s
     /// ```
     #[must_use]
     #[stable(feature = "checked_duration_since", since = "1.39.0")]